@@ -1,5 +1,25 @@
+use core::fmt::Display;
+use core::ops::{Index, IndexMut};
+
+use glam::UVec2;
 use itertools::Itertools;
 
+/// `(row, column)` offsets of the four neighbors, clockwise from up.
+const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// `(row, column)` offsets of the eight neighbors, clockwise from up.
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Array2D<T> {
     items: Box<[T]>,
     columns_count: usize,
@@ -93,6 +113,378 @@ impl<T> Array2D<T> {
             ((row, column), item)
         })
     }
+
+    /// `((row, column), item)`
+    #[inline]
+    pub fn into_iter_with_index(self) -> impl DoubleEndedIterator<Item = ((usize, usize), T)> {
+        let columns_count = self.columns_count;
+        self.items
+            .into_vec()
+            .into_iter()
+            .enumerate()
+            .map(move |(i, item)| {
+                let row = i / columns_count;
+                let column = i % columns_count;
+                ((row, column), item)
+            })
+    }
+
+    /// Iterates over the up, right, down and left neighbors of a cell that are in the array.
+    ///
+    /// `((row, column), item)`
+    #[inline]
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + Clone + '_ {
+        self.neighbors(row, column, &NEIGHBORS_4)
+    }
+
+    /// Iterates over the eight neighbors of a cell, including the diagonals, that are in the array.
+    ///
+    /// `((row, column), item)`
+    #[inline]
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + Clone + '_ {
+        self.neighbors(row, column, &NEIGHBORS_8)
+    }
+
+    #[inline]
+    fn neighbors(
+        &self,
+        row: usize,
+        column: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + Clone + '_ {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr)?;
+            let column = column.checked_add_signed(dc)?;
+            let item = self.get(row, column)?;
+            Some(((row, column), item))
+        })
+    }
+
+    #[inline]
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.rows().nth(row)
+    }
+
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + Clone + '_ {
+        // NOTE: an array without columns has no items, so it has no rows either.
+        self.items.chunks_exact(self.columns_count.max(1))
+    }
+
+    /// Iterates over the items of a column, from top to bottom.
+    ///
+    /// The iterator is empty if the column is out of the array.
+    #[inline]
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> + Clone + '_ {
+        let rows_count = if column < self.columns_count {
+            self.size().rows_count()
+        } else {
+            0
+        };
+
+        self.items[column.min(self.items.len())..]
+            .iter()
+            .step_by(self.columns_count.max(1))
+            .take(rows_count)
+    }
+
+    #[inline]
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone + '_> + Clone + '_ {
+        (0..self.columns_count).map(|column| self.column(column))
+    }
+
+    /// Iterates over the items of the diagonal that goes down-right from a cell.
+    #[inline]
+    pub fn diagonal(&self, row: usize, column: usize) -> impl Iterator<Item = &T> + Clone + '_ {
+        (0..).map_while(move |i| self.get(row + i, column + i))
+    }
+
+    /// Iterates over the items of the diagonal that goes down-left from a cell.
+    #[inline]
+    pub fn anti_diagonal(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = &T> + Clone + '_ {
+        (0..).map_while(move |i| self.get(row + i, column.checked_sub(i)?))
+    }
+
+    /// Iterates over all the diagonals that go down-right,
+    /// from the bottom-left corner to the top-right corner.
+    #[inline]
+    pub fn diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone + '_> + Clone + '_ {
+        let rows = (0..self.size().rows_count()).rev().map(|row| (row, 0));
+        let columns = (1..self.columns_count).map(|column| (0, column));
+
+        rows.chain(columns)
+            .map(|(row, column)| self.diagonal(row, column))
+    }
+
+    /// Iterates over all the diagonals that go down-left,
+    /// from the top-left corner to the bottom-right corner.
+    #[inline]
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone + '_> + Clone + '_ {
+        let last_column = self.columns_count.saturating_sub(1);
+        let columns = (0..self.columns_count).map(|column| (0, column));
+        let rows = (1..self.size().rows_count()).map(move |row| (row, last_column));
+
+        columns
+            .chain(rows)
+            .map(|(row, column)| self.anti_diagonal(row, column))
+    }
+
+    /// Returns a view on the sub-array of `size` whose top-left corner is at `(row, column)`,
+    /// or [`None`] if it doesn't fit in the array.
+    #[inline]
+    pub fn window(&self, row: usize, column: usize, size: Size) -> Option<Window<'_, T>> {
+        if row + size.rows_count() > self.size().rows_count()
+            || column + size.columns_count() > self.columns_count
+        {
+            return None;
+        }
+
+        Some(Window {
+            array: self,
+            row,
+            column,
+            size,
+        })
+    }
+
+    /// Iterates over all the sub-arrays of `size`, row by row.
+    ///
+    /// `((row, column), window)`
+    #[inline]
+    pub fn windows(
+        &self,
+        size: Size,
+    ) -> impl Iterator<Item = ((usize, usize), Window<'_, T>)> + Clone + '_ {
+        let rows = 0..(self.size().rows_count() + 1).saturating_sub(size.rows_count());
+        let columns = 0..(self.columns_count + 1).saturating_sub(size.columns_count());
+
+        rows.cartesian_product(columns)
+            .filter_map(move |(row, column)| Some(((row, column), self.window(row, column, size)?)))
+    }
+
+    #[inline]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Array2D<U> {
+        Array2D {
+            items: self.items.iter().map(f).collect(),
+            columns_count: self.columns_count,
+        }
+    }
+
+    /// Renders the array, one line per row, mapping each item with `f`.
+    ///
+    /// Useful to print the array while debugging.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .join("\n")
+    }
+
+    /// Combines the items of two arrays of the same size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays haven't the same size.
+    #[inline]
+    pub fn zip_with<U, V>(&self, other: &Array2D<U>, mut f: impl FnMut(&T, &U) -> V) -> Array2D<V> {
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "the arrays haven't the same size"
+        );
+
+        Array2D {
+            items: self
+                .items
+                .iter()
+                .zip(other.items.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            columns_count: self.columns_count,
+        }
+    }
+}
+
+impl<T: Clone> Array2D<T> {
+    #[must_use]
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        let size = self.size();
+        Array2D::from_elem(
+            Size::from_rows_columns(size.columns_count(), size.rows_count()),
+            |row, column| self[(column, row)].clone(),
+        )
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn rotated_clockwise(&self) -> Self {
+        let size = self.size();
+        let last_row = size.rows_count().saturating_sub(1);
+        Array2D::from_elem(
+            Size::from_rows_columns(size.columns_count(), size.rows_count()),
+            |row, column| self[(last_row - column, row)].clone(),
+        )
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn rotated_anti_clockwise(&self) -> Self {
+        let size = self.size();
+        let last_column = size.columns_count().saturating_sub(1);
+        Array2D::from_elem(
+            Size::from_rows_columns(size.columns_count(), size.rows_count()),
+            |row, column| self[(column, last_column - row)].clone(),
+        )
+    }
+
+    /// Mirrors the array along the vertical axis (i.e. left becomes right).
+    #[must_use]
+    #[inline]
+    pub fn flipped_horizontally(&self) -> Self {
+        let last_column = self.columns_count.saturating_sub(1);
+        Array2D::from_elem(self.size(), |row, column| {
+            self[(row, last_column - column)].clone()
+        })
+    }
+
+    /// Mirrors the array along the horizontal axis (i.e. up becomes down).
+    #[must_use]
+    #[inline]
+    pub fn flipped_vertically(&self) -> Self {
+        let last_row = self.size().rows_count().saturating_sub(1);
+        Array2D::from_elem(self.size(), |row, column| {
+            self[(last_row - row, column)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Array2D<T> {
+    type Output = T;
+
+    /// `(row, column)`
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        self.get(row, column).expect("index out of the array")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Array2D<T> {
+    /// `(row, column)`
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, column).expect("index out of the array")
+    }
+}
+
+impl<T> Index<UVec2> for Array2D<T> {
+    type Output = T;
+
+    /// The positive directions are down for Y axis and right for X axis.
+    #[inline]
+    fn index(&self, pos: UVec2) -> &Self::Output {
+        &self[(pos.y.try_into().unwrap(), pos.x.try_into().unwrap())]
+    }
+}
+
+impl<T> IndexMut<UVec2> for Array2D<T> {
+    /// The positive directions are down for Y axis and right for X axis.
+    #[inline]
+    fn index_mut(&mut self, pos: UVec2) -> &mut Self::Output {
+        &mut self[(pos.y.try_into().unwrap(), pos.x.try_into().unwrap())]
+    }
+}
+
+impl Display for Array2D<u8> {
+    /// Prints the bytes as characters, one line per row.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.render(|&c| char::from(c)))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A view on a rectangular part of an [`Array2D`].
+#[derive(Debug)]
+pub struct Window<'a, T> {
+    array: &'a Array2D<T>,
+    row: usize,
+    column: usize,
+    size: Size,
+}
+
+impl<T> Clone for Window<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Window<'_, T> {}
+
+impl<'a, T> Window<'a, T> {
+    #[inline]
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// The `(row, column)` of the top-left corner of the window in the array.
+    #[inline]
+    pub fn origin(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        if row >= self.size.rows_count() || column >= self.size.columns_count() {
+            return None;
+        }
+        self.array.get(self.row + row, self.column + column)
+    }
+
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + Clone + 'a {
+        let columns = self.column..self.column + self.size.columns_count();
+        self.array
+            .rows()
+            .skip(self.row)
+            .take(self.size.rows_count())
+            .map(move |row| &row[columns.clone()])
+    }
+}
+
+impl<T: Clone> Window<'_, T> {
+    #[inline]
+    pub fn to_array2d(&self) -> Array2D<T> {
+        Array2D::from_elem(self.size, |row, column| self[(row, column)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Window<'_, T> {
+    type Output = T;
+
+    /// `(row, column)`
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        self.get(row, column).expect("index out of the window")
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -127,10 +519,51 @@ impl Size {
         self.columns_count
     }
 
+    /// The number of rows, `0` if there are no columns.
     #[inline]
     pub fn rows_count(&self) -> usize {
-        self.items_count / self.columns_count
+        self.items_count
+            .checked_div(self.columns_count)
+            .unwrap_or(0)
     }
 }
 
 /* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Array2D, Size};
+
+    #[test]
+    fn handles_empty_arrays() {
+        for size in [Size::from_rows_columns(0, 0), Size::from_rows_columns(3, 0)] {
+            let array = Array2D::from_elem(size, |_, _| 0_u8);
+            assert_eq!(array.size().rows_count(), 0);
+            assert_eq!(array.rows().count(), 0);
+            assert_eq!(array.column(0).count(), 0);
+            assert_eq!(array.rotated_clockwise().size().items_count(), 0);
+            assert_eq!(array.rotated_anti_clockwise().size().items_count(), 0);
+            assert_eq!(array.flipped_horizontally().size().items_count(), 0);
+            assert_eq!(array.flipped_vertically().size().items_count(), 0);
+            assert_eq!(array.to_string(), "");
+        }
+
+        let array = Array2D::from_elem(Size::from_rows_columns(0, 3), |_, _| 0_u8);
+        assert_eq!(array.rows().count(), 0);
+        assert_eq!(array.column(1).count(), 0);
+        assert_eq!(array.rotated_clockwise().size().items_count(), 0);
+    }
+
+    #[test]
+    fn transforms() {
+        let array = Array2D::from_elem(Size::from_rows_columns(2, 3), |row, column| {
+            b"abcdef"[row * 3 + column]
+        });
+        assert_eq!(array.to_string(), "abc\ndef");
+        assert_eq!(array.rotated_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(array.rotated_anti_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(array.flipped_horizontally().to_string(), "cba\nfed");
+        assert_eq!(array.flipped_vertically().to_string(), "def\nabc");
+        assert_eq!(array.column(1).copied().collect::<Vec<_>>(), b"be");
+    }
+}
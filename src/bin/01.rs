@@ -1,13 +1,9 @@
+use advent_of_code::{counter::Counter, parse_lines};
+
 advent_of_code::solution!();
 
 fn parse(input: &str) -> impl Iterator<Item = (u32, u32)> + '_ {
-    input.lines().map(|line| {
-        let (l, r) = line.split_once("   ").unwrap();
-        let l: u32 = l.parse().unwrap();
-        let r: u32 = r.parse().unwrap();
-
-        (l, r)
-    })
+    parse_lines(input).map(Result::unwrap)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -22,22 +18,14 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let values = parse(input);
-
-    let mut left = Vec::new();
-    let mut right = std::collections::HashMap::new();
-
-    for (l, r) in values {
-        left.push(l);
-        *right.entry(r).or_insert(0) += 1;
-    }
+    let (left, right) = parse(input).collect::<(Vec<_>, Counter<_>)>();
 
     let score = left
         .iter()
-        .map(|l| l * right.get(l).copied().unwrap_or(0))
-        .sum();
+        .map(|l| u64::from(*l) * right.get(l))
+        .sum::<u64>();
 
-    Some(score)
+    score.try_into().ok()
 }
 
 #[cfg(test)]
@@ -1,11 +1,14 @@
+use advent_of_code::ints;
 use itertools::Itertools;
 
 advent_of_code::solution!();
 
-pub fn parse(input: &str) -> impl Iterator<Item: Iterator<Item = u32>> + '_ {
+/// Returns the levels of each report, or [`None`] if a level overflows.
+pub fn parse(input: &str) -> Option<Vec<Vec<u32>>> {
     input
         .lines()
-        .map(|line| line.split_whitespace().map(|n| n.parse().unwrap()))
+        .map(|line| ints::iter(line).collect::<Result<Vec<_>, _>>().ok())
+        .collect()
 }
 
 fn check_value(cmp: core::cmp::Ordering) -> impl Fn((u32, u32)) -> bool {
@@ -33,7 +36,7 @@ fn is_report_safe(report: impl IntoIterator<Item = u32>) -> bool {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let reports = parse(input);
+    let reports = parse(input)?;
 
     let mut are_safe = 0;
     for report in reports {
@@ -46,13 +49,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let reports = parse(input);
+    let reports = parse(input)?;
 
     let mut are_safe = 0;
 
     for report in reports {
-        let report = report.collect_vec();
-
         // Note: brute force
         for i in 0..report.len() {
             let head = &report[..i];
@@ -85,4 +86,10 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(4));
     }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(part_one("1 2 3\n4 99999999999 5"), None);
+        assert_eq!(part_two("1 2 3\n4 99999999999 5"), None);
+    }
 }
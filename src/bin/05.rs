@@ -1,31 +1,25 @@
+use advent_of_code::{parse_lines_with, template::Input};
 use itertools::Itertools;
 
 advent_of_code::solution!();
 
 pub fn parse(
-    input: &str,
+    input: &Input,
 ) -> (
     impl Iterator<Item = (u32, u32)> + '_,
     impl Iterator<Item: Iterator<Item = u32>> + '_,
 ) {
-    let (ordering_rules, updates) = input.split_once("\n\n").unwrap();
+    let (ordering_rules, updates) = input.blocks().collect_tuple().unwrap();
 
-    let ordering_rules = ordering_rules.lines().map(|line| {
-        let (before, after) = line.split_once("|").unwrap();
-        (
-            before.parse::<u32>().unwrap(),
-            after.parse::<u32>().unwrap(),
-        )
-    });
+    let ordering_rules = parse_lines_with(ordering_rules, "|").map(Result::unwrap);
 
-    let updates = updates
-        .lines()
-        .map(|line| line.split(",").map(|x| x.parse::<u32>().unwrap()));
+    let updates =
+        parse_lines_with::<Vec<u32>>(updates, ",").map(|update| update.unwrap().into_iter());
 
     (ordering_rules, updates)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let (ordering_rules, updates) = parse(input);
 
     let ordering_rules = ordering_rules.into_group_map();
@@ -54,7 +48,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let (ordering_rules, updates) = parse(input);
 
     let ordering_rules = ordering_rules.into_group_map();
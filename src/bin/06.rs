@@ -1,34 +1,21 @@
-use std::{collections::HashSet, ops::ControlFlow};
-
-use advent_of_code::{ascii_map_size, parse_ascii_map_ivec, Dir, MapSize};
+use advent_of_code::{bit_grid::BitGrid, parse_ascii_map_ivec, Dir, MapSize};
 use glam::IVec2 as Pos;
 
 advent_of_code::solution!();
 
 struct Input {
-    obstacles: HashSet<Pos>,
+    obstacles: BitGrid,
     start_pos: Pos,
     map_size: MapSize,
 }
 
 fn parse(input: &str) -> Input {
-    let map_size = ascii_map_size(input);
-
-    let mut obstacles = HashSet::new();
-    let mut start_pos = None;
-
-    parse_ascii_map_ivec(input).for_each(|(pos, c)| match c {
-        b'#' => {
-            obstacles.insert(pos);
-        }
-        b'^' => {
-            debug_assert!(start_pos.is_none());
-            start_pos = Some(pos);
-        }
-        _ => {}
-    });
+    let obstacles = BitGrid::from_ascii(input, |c| c == b'#');
+    let map_size = obstacles.map_size();
 
-    let start_pos = start_pos.expect("no guard pos");
+    let start_pos = parse_ascii_map_ivec(input)
+        .find_map(|(pos, c)| (c == b'^').then_some(pos))
+        .expect("no guard pos");
 
     Input {
         obstacles,
@@ -37,110 +24,81 @@ fn parse(input: &str) -> Input {
     }
 }
 
-/// Simulates the guard's movement.
+/// Moves the guard one cell forward, or turns it if there is an obstacle.
 ///
-/// Returns `true` is `visit` returns [`ControlFlow::Break`] and
-/// `false` if the guard leaves the map.
-fn explore(
-    start_pos: Pos,
+/// Returns [`None`] if the guard leaves the map.
+fn step_guard(
+    (pos, facing_direction): (Pos, Dir),
     map_size: MapSize,
     is_obstacle: impl Fn(Pos) -> bool,
-    mut visit: impl FnMut(Pos, Dir) -> ControlFlow<()>,
-) -> bool {
-    let mut guard_pos = start_pos;
-    let mut facing_direction = Dir::Up;
-
-    if visit(guard_pos, facing_direction).is_break() {
-        return true;
+) -> Option<(Pos, Dir)> {
+    let next_pos = pos + facing_direction.as_vec_down_right();
+
+    if !map_size.contains(next_pos) {
+        None
+    } else if is_obstacle(next_pos) {
+        Some((pos, facing_direction.rotated_clockwise()))
+    } else {
+        Some((next_pos, facing_direction))
     }
+}
 
-    loop {
-        let dir = facing_direction.as_vec_down_right();
-
-        'walk_foward: loop {
-            let next_pos = guard_pos + dir;
-
-            if !map_size.contains(next_pos) {
-                return false;
-            }
-
-            if is_obstacle(next_pos) {
-                facing_direction = facing_direction.rotated_clockwise();
-                break 'walk_foward;
-            } else {
-                guard_pos = next_pos;
-                if visit(guard_pos, facing_direction).is_break() {
-                    return true;
-                }
-            }
-        }
-    }
+/// Returns the positions visited by the guard until it leaves the map.
+fn visited_positions(input: &Input) -> BitGrid {
+    let mut visited = BitGrid::new(input.obstacles.width(), input.obstacles.height());
+    visited.extend(
+        std::iter::successors(Some((input.start_pos, Dir::Up)), |&guard| {
+            step_guard(guard, input.map_size, |pos| input.obstacles.contains(pos))
+        })
+        .map(|(pos, _)| pos),
+    );
+    visited
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let Input {
-        obstacles,
-        start_pos,
-        map_size,
-    } = parse(input);
-
-    let mut visited = HashSet::new();
-
-    explore(
-        start_pos,
-        map_size,
-        |pos| obstacles.contains(&pos),
-        |pos, _| {
-            visited.insert(pos);
-            ControlFlow::Continue(())
-        },
-    );
+    let visited = visited_positions(&parse(input));
 
     Some(visited.len().try_into().unwrap())
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
+    let input = parse(input);
     let Input {
         obstacles,
         start_pos,
         map_size,
-    } = parse(input);
+    } = &input;
 
-    let mut visited = HashSet::new();
-    explore(
-        start_pos,
-        map_size,
-        |pos| obstacles.contains(&pos),
-        |pos, _| {
-            visited.insert(pos);
-            ControlFlow::Continue(())
-        },
-    );
+    let mut visited = visited_positions(&input);
 
     // We are not allowed to put an obstacle where the guard is.
-    visited.remove(&start_pos);
-
-    let mut possibility_to_make_a_loop = 0;
-
-    for new_obstacle in visited {
-        let mut states = HashSet::new();
-
-        let is_loop = explore(
-            start_pos,
-            map_size,
-            |pos| pos == new_obstacle || obstacles.contains(&pos),
-            |pos, facing_direction| match states.insert((pos, facing_direction)) {
-                true => ControlFlow::Continue(()),
-                false => ControlFlow::Break(()),
-            },
-        );
-
-        if is_loop {
-            possibility_to_make_a_loop += 1;
-        }
-    }
+    visited.remove(*start_pos);
+
+    // The visited positions, for each facing direction.
+    let mut states = Dir::ALL.map(|_| BitGrid::new(obstacles.width(), obstacles.height()));
+
+    let possibility_to_make_a_loop = visited
+        .iter()
+        .filter(|&new_obstacle: &Pos| {
+            states.iter_mut().for_each(BitGrid::clear);
+
+            let mut guard = Some((*start_pos, Dir::Up));
+            while let Some((pos, facing_direction)) = guard {
+                // The guard loops as soon as it is back in a state it has already been in.
+                if !states[facing_direction as usize].insert(pos) {
+                    return true;
+                }
+
+                guard = step_guard((pos, facing_direction), *map_size, |pos| {
+                    pos == new_obstacle || obstacles.contains(pos)
+                });
+            }
+
+            false
+        })
+        .count();
 
-    Some(possibility_to_make_a_loop)
+    Some(possibility_to_make_a_loop.try_into().unwrap())
 }
 
 #[cfg(test)]
@@ -1,22 +1,31 @@
-advent_of_code::solution!();
+use advent_of_code::{ints, math::concat_digits};
 
-fn parse(input: &str) -> impl Iterator<Item = (u64, impl Iterator<Item = u64> + '_)> {
-    input.lines().map(|line| {
-        let (result, values) = line.split_once(':').unwrap();
+advent_of_code::solution!();
 
-        let result = result.parse().unwrap();
-        let values = values.split_whitespace().map(|n| n.parse().unwrap());
+/// Returns the result and the values of each equation,
+/// or [`None`] if a number overflows or a line has no values.
+fn parse(input: &str) -> Option<Vec<(u64, Vec<u64>)>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut values = ints::iter(line).collect::<Result<Vec<_>, _>>().ok()?;
+            if values.len() < 2 {
+                return None;
+            }
+            let result = values.remove(0);
 
-        (result, values)
-    })
+            Some((result, values))
+        })
+        .collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let tests = parse(input);
+    let tests = parse(input)?;
 
-    let total = tests.fold(0, |acc, (result, mut values)| {
+    let total = tests.into_iter().fold(0, |acc, (result, values)| {
+        let mut values = values.into_iter();
         let mut totals = Vec::new();
-        totals.push(values.next().unwrap());
+        totals.extend(values.next());
 
         let mut current_totals = Vec::new();
 
@@ -40,7 +49,7 @@ pub fn part_one(input: &str) -> Option<u64> {
             }
         }
 
-        if totals.iter().any(|&x| x == result) {
+        if totals.contains(&result) {
             acc + result
         } else {
             acc
@@ -50,18 +59,13 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(total)
 }
 
-fn concat(lhs: u64, rhs: u64) -> u64 {
-    let pow_10 = rhs.ilog10();
-
-    lhs * 10_u64.pow(pow_10 + 1) + rhs
-}
-
 pub fn part_two(input: &str) -> Option<u64> {
-    let tests = parse(input);
+    let tests = parse(input)?;
 
-    let total = tests.fold(0, |acc, (result, mut values)| {
+    let total = tests.into_iter().fold(0, |acc, (result, values)| {
+        let mut values = values.into_iter();
         let mut totals = Vec::new();
-        totals.push(values.next().unwrap());
+        totals.extend(values.next());
 
         let mut current_totals = Vec::new();
 
@@ -70,7 +74,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             for x in current_totals.drain(..) {
                 let plus = x + value;
                 let mul = x * value;
-                let concat = concat(x, value);
+                let concat = concat_digits(x, value);
 
                 if plus <= result {
                     totals.push(plus);
@@ -90,7 +94,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             }
         }
 
-        if totals.iter().any(|&x| x == result) {
+        if totals.contains(&result) {
             acc + result
         } else {
             acc
@@ -121,8 +125,14 @@ mod tests {
     fn test_concat() {
         #![allow(clippy::inconsistent_digit_grouping)]
 
-        assert_eq!(concat(64, 132), 64_132);
-        assert_eq!(concat(72, 9), 72_9);
-        assert_eq!(concat(10001, 9757575), 10001_9757575);
+        assert_eq!(concat_digits(64_u64, 132), 64_132);
+        assert_eq!(concat_digits(72_u64, 9), 72_9);
+        assert_eq!(concat_digits(10001_u64, 9757575), 10001_9757575);
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(part_one("190: 10 19\n99999999999999999999: 1 2"), None);
+        assert_eq!(part_two("190: 10 19\n3: 99999999999999999999 2"), None);
     }
 }
@@ -1,3 +1,4 @@
+use advent_of_code::range_set::RangeSet;
 use num::Integer;
 
 advent_of_code::solution!();
@@ -24,11 +25,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     // The amount of data to transfer from the end
     let mut pending_file_to_transfer = 0;
 
-    'outer: loop {
-        let Some(file_size) = data.next() else {
-            break;
-        };
-
+    'outer: while let Some(file_size) = data.next() {
         for _ in 0..file_size {
             checksum += block_position * left_file_id;
             block_position += 1;
@@ -93,14 +90,8 @@ pub fn part_two(input: &str) -> Option<u64> {
         size: usize,
     }
 
-    struct Space {
-        pos: usize,
-        size: usize,
-    }
-
-    let half_len = input.len() / 2;
-    let mut files = Vec::with_capacity(half_len + 1);
-    let mut spaces = Vec::with_capacity(half_len);
+    let mut files = Vec::with_capacity(input.len() / 2 + 1);
+    let mut spaces = RangeSet::new();
 
     let data = input
         .bytes()
@@ -120,19 +111,18 @@ pub fn part_two(input: &str) -> Option<u64> {
                 size,
             });
         } else {
-            spaces.push(Space { pos, size });
+            spaces.insert(pos..pos + size);
         }
         pos += size;
     }
 
     for file in files.iter_mut().rev() {
         if let Some(space) = spaces
-            .iter_mut()
-            .find(|space| space.pos < file.pos && space.size >= file.size)
+            .first_fit(file.size)
+            .filter(|space| space.start < file.pos)
         {
-            file.pos = space.pos;
-            space.size -= file.size;
-            space.pos += file.size;
+            file.pos = space.start;
+            spaces.remove(space.start..space.start + file.size);
         }
     }
 
@@ -34,33 +34,9 @@ pub fn part_one(input: &str) -> Option<u32> {
 
             let target = value + 1;
 
-            // Up
-            if let Some(r) = r.checked_sub(1) {
-                if map.get(r, c).copied().unwrap() == target {
-                    to_explore.push(((r, c), target));
-                }
-            }
-
-            // Down
-            {
-                let r = r + 1;
-                if map.get(r, c).copied().is_some_and(|x| x == target) {
-                    to_explore.push(((r, c), target));
-                }
-            }
-
-            // Left
-            if let Some(c) = c.checked_sub(1) {
-                if map.get(r, c).copied().unwrap() == target {
-                    to_explore.push(((r, c), target));
-                }
-            }
-
-            // Right
-            {
-                let c = c + 1;
-                if map.get(r, c).copied().is_some_and(|x| x == target) {
-                    to_explore.push(((r, c), target));
+            for (pos, &neighbor) in map.neighbors4(r, c) {
+                if neighbor == target {
+                    to_explore.push((pos, target));
                 }
             }
         }
@@ -94,33 +70,9 @@ pub fn part_two(input: &str) -> Option<u32> {
 
         let target = value + 1;
 
-        // Up
-        if let Some(r) = r.checked_sub(1) {
-            if map.get(r, c).copied().unwrap() == target {
-                to_explore.push(((r, c), target));
-            }
-        }
-
-        // Down
-        {
-            let r = r + 1;
-            if map.get(r, c).copied().is_some_and(|x| x == target) {
-                to_explore.push(((r, c), target));
-            }
-        }
-
-        // Left
-        if let Some(c) = c.checked_sub(1) {
-            if map.get(r, c).copied().unwrap() == target {
-                to_explore.push(((r, c), target));
-            }
-        }
-
-        // Right
-        {
-            let c = c + 1;
-            if map.get(r, c).copied().is_some_and(|x| x == target) {
-                to_explore.push(((r, c), target));
+        for (pos, &neighbor) in map.neighbors4(r, c) {
+            if neighbor == target {
+                to_explore.push((pos, target));
             }
         }
     }
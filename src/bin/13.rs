@@ -1,4 +1,4 @@
-use advent_of_code::int_linear_solve2;
+use advent_of_code::{linear, scan};
 use glam::{i64vec2, ivec2, I64Vec2, IVec2};
 use itertools::Itertools;
 
@@ -19,31 +19,13 @@ fn parse(input: &str) -> impl Iterator<Item = Machine> + '_ {
         // The empty line between inputs
         let _ = lines.next();
 
-        const BUTTON_A_PREFIX: &str = "Button A: X+";
-        const BUTTON_B_PREFIX: &str = "Button B: X+";
-        const BUTTON_IN_BETWEEN: &str = ", Y+";
-        const PRIZE_PREFIX: &str = "Prize: X=";
-        const PRIZE_IN_BETWEEN: &str = ", Y=";
-
-        let (ax, ay) = a
-            .strip_prefix(BUTTON_A_PREFIX)
-            .unwrap()
-            .split_once(BUTTON_IN_BETWEEN)
-            .unwrap();
-        let (bx, by) = b
-            .strip_prefix(BUTTON_B_PREFIX)
-            .unwrap()
-            .split_once(BUTTON_IN_BETWEEN)
-            .unwrap();
-        let (px, py) = prize
-            .strip_prefix(PRIZE_PREFIX)
-            .unwrap()
-            .split_once(PRIZE_IN_BETWEEN)
-            .unwrap();
-
-        let button_a = ivec2(ax.parse().unwrap(), ay.parse().unwrap());
-        let button_b = ivec2(bx.parse().unwrap(), by.parse().unwrap());
-        let prize = ivec2(px.parse().unwrap(), py.parse().unwrap());
+        let (ax, ay) = scan!(a, "Button A: X+{}, Y+{}" => (i32, i32)).unwrap();
+        let (bx, by) = scan!(b, "Button B: X+{}, Y+{}" => (i32, i32)).unwrap();
+        let (px, py) = scan!(prize, "Prize: X={}, Y={}" => (i32, i32)).unwrap();
+
+        let button_a = ivec2(ax, ay);
+        let button_b = ivec2(bx, by);
+        let prize = ivec2(px, py);
 
         Some(Machine {
             button_a,
@@ -53,49 +35,41 @@ fn parse(input: &str) -> impl Iterator<Item = Machine> + '_ {
     })
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let machines = parse(input);
-
-    let mut needed_tokens = 0;
-
-    for machine in machines {
-        let a = machine.button_a.x;
-        let b = machine.button_b.x;
-        let c = machine.button_a.y;
-        let d = machine.button_b.y;
-
-        if let Some((a_count, b_count)) =
-            int_linear_solve2(machine.prize.x, machine.prize.y, a, b, c, d)
-                .and_then(|(a, b)| Some((u32::try_from(a).ok()?, u32::try_from(b).ok()?)))
-        {
-            needed_tokens += 3 * a_count + b_count;
-        }
-    }
+/// Returns the tokens needed to win the prize, or [`None`] if it cannot be won.
+fn needed_tokens(button_a: I64Vec2, button_b: I64Vec2, prize: I64Vec2) -> Option<i64> {
+    let solution = linear::solve(
+        &[vec![button_a.x, button_b.x], vec![button_a.y, button_b.y]],
+        &[prize.x, prize.y],
+    );
 
-    Some(needed_tokens)
+    let (_, tokens) = solution.min_cost_non_negative(&[3, 1])?;
+    Some(tokens)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let machines = parse(input);
-
-    let mut needed_tokens = 0;
-
-    for machine in machines {
-        let a: i64 = machine.button_a.x.into();
-        let b: i64 = machine.button_b.x.into();
-        let c: i64 = machine.button_a.y.into();
-        let d: i64 = machine.button_b.y.into();
+pub fn part_one(input: &str) -> Option<u32> {
+    let needed_tokens: i64 = parse(input)
+        .filter_map(|machine| {
+            needed_tokens(
+                machine.button_a.into(),
+                machine.button_b.into(),
+                machine.prize.into(),
+            )
+        })
+        .sum();
 
-        let prize = I64Vec2::from(machine.prize) + i64vec2(10_000_000_000_000, 10_000_000_000_000);
+    Some(needed_tokens.try_into().unwrap())
+}
 
-        if let Some((a_count, b_count)) = int_linear_solve2(prize.x, prize.y, a, b, c, d)
-            .and_then(|(a, b)| Some((u64::try_from(a).ok()?, u64::try_from(b).ok()?)))
-        {
-            needed_tokens += 3 * a_count + b_count;
-        }
-    }
+pub fn part_two(input: &str) -> Option<u64> {
+    let needed_tokens: i64 = parse(input)
+        .filter_map(|machine| {
+            let prize =
+                I64Vec2::from(machine.prize) + i64vec2(10_000_000_000_000, 10_000_000_000_000);
+            needed_tokens(machine.button_a.into(), machine.button_b.into(), prize)
+        })
+        .sum();
 
-    Some(needed_tokens)
+    Some(needed_tokens.try_into().unwrap())
 }
 
 #[cfg(test)]
@@ -107,4 +81,23 @@ mod tests {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(480));
     }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(875_318_608_908));
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        // A moves 4 times as far as B for 3 times the cost, so pressing A is cheaper.
+        let result = needed_tokens(i64vec2(4, 4), i64vec2(1, 1), i64vec2(10, 10));
+        assert_eq!(result, Some(8));
+
+        let result = needed_tokens(i64vec2(2, 2), i64vec2(1, 1), i64vec2(10, 10));
+        assert_eq!(result, Some(10));
+
+        let result = needed_tokens(i64vec2(4, 4), i64vec2(2, 2), i64vec2(7, 7));
+        assert_eq!(result, None);
+    }
 }
@@ -1,3 +1,4 @@
+use advent_of_code::{math::crt, scan, Torus};
 use glam::{ivec2, IVec2};
 use itertools::Itertools;
 
@@ -10,54 +11,27 @@ struct Robot {
 
 fn parse(input: &str) -> impl Iterator<Item = Robot> + '_ {
     input.lines().map(|line| {
-        let (p, v) = line.split_once(' ').unwrap();
+        let (px, py, vx, vy) = scan!(line, "p={},{} v={},{}" => (i32, i32, i32, i32)).unwrap();
 
-        let p = p.strip_prefix("p=").unwrap();
-        let v = v.strip_prefix("v=").unwrap();
-
-        let (px, py) = p.split_once(',').unwrap();
-        let (vx, vy) = v.split_once(',').unwrap();
-
-        let pos = ivec2(px.parse().unwrap(), py.parse().unwrap());
-        let vel = ivec2(vx.parse().unwrap(), vy.parse().unwrap());
-
-        Robot { pos, vel }
+        Robot {
+            pos: ivec2(px, py),
+            vel: ivec2(vx, vy),
+        }
     })
 }
 
-fn solve_1(input: &str, area_size: IVec2) -> u32 {
+fn solve_1(input: &str, torus: Torus) -> u32 {
     const SECONDS: i32 = 100;
 
     let robots = parse(input);
     let mut robot_count_in_quadrants = [0; 4];
 
-    let half = area_size / 2;
-
     robots.for_each(|Robot { pos, vel }| {
-        let pos = pos + vel * SECONDS;
+        let pos = torus.advance(pos, vel, SECONDS);
 
-        let mut x = pos.x % area_size.x;
-        if x < 0 {
-            x += area_size.x;
+        if let Some(idx) = torus.quadrant(pos) {
+            robot_count_in_quadrants[idx] += 1;
         }
-
-        let mut y = pos.y % area_size.y;
-        if y < 0 {
-            y += area_size.y;
-        }
-
-        if x == half.x || y == half.y {
-            return;
-        }
-
-        let idx = match (x < half.x, y < half.y) {
-            (true, true) => 0,
-            (true, false) => 1,
-            (false, true) => 2,
-            (false, false) => 3,
-        };
-
-        robot_count_in_quadrants[idx] += 1;
     });
 
     robot_count_in_quadrants
@@ -67,44 +41,45 @@ fn solve_1(input: &str, area_size: IVec2) -> u32 {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    Some(solve_1(input, ivec2(101, 103)))
+    Some(solve_1(input, Torus::new(101, 103)))
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    const WIDTH: i32 = 101;
-    const HEIGHT: i32 = 103;
+    let torus = Torus::new(101, 103);
 
-    let mut robots = parse(input).collect_vec();
-    let robot_count = i32::try_from(robots.len()).unwrap();
+    let robots = parse(input).collect_vec();
 
-    for i in 1.. {
-        robots.iter_mut().for_each(|Robot { pos, vel }| {
-            let mut x = (pos.x + vel.x) % WIDTH;
-            if x < 0 {
-                x += WIDTH;
-            }
-
-            let mut y = (pos.y + vel.y) % HEIGHT;
-            if y < 0 {
-                y += HEIGHT;
-            }
-
-            *pos = ivec2(x, y);
-        });
-
-        let center = robots
+    // The positions on each axis repeat with a period of the size of the area,
+    // and the tree appears when the robots are the most packed together on both axes.
+    let positions_at = |time| {
+        robots
             .iter()
-            .map(|robot| robot.pos)
-            .fold(IVec2::ZERO, |a, b| a + b)
-            / robot_count;
+            .map(move |robot| torus.advance(robot.pos, robot.vel, time))
+    };
+    let x_time = most_packed_time(torus.width(), |time| positions_at(time).map(|pos| pos.x));
+    let y_time = most_packed_time(torus.height(), |time| positions_at(time).map(|pos| pos.y));
 
-        // NOTE: values found empirically
-        if center.x >= 55 && center.y >= 55 {
-            return Some(i);
-        }
-    }
+    let (time, _) = crt([(x_time, torus.width()), (y_time, torus.height())])?;
 
-    unreachable!();
+    Some(time.try_into().unwrap())
+}
+
+/// Returns the time in `0..period` at which the positions on an axis have the lowest variance.
+fn most_packed_time<I: Iterator<Item = i32>>(period: i32, positions_at: impl Fn(i32) -> I) -> i32 {
+    (0..period)
+        .min_by_key(|&time| {
+            let (count, sum, sum_of_squares) = positions_at(time).fold(
+                (0_i64, 0_i64, 0_i64),
+                |(count, sum, sum_of_squares), pos| {
+                    let pos = i64::from(pos);
+                    (count + 1, sum + pos, sum_of_squares + pos * pos)
+                },
+            );
+
+            // The variance times `count²`.
+            count * sum_of_squares - sum * sum
+        })
+        .unwrap()
 }
 
 #[cfg(test)]
@@ -114,7 +89,16 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = &advent_of_code::template::read_file("examples", DAY);
-        let result = solve_1(input, ivec2(11, 7));
+        let result = solve_1(input, Torus::new(11, 7));
         assert_eq!(result, 12);
     }
+
+    #[test]
+    fn test_part_two() {
+        // The robots of the example all gather in an 8x8 square after 4321 seconds.
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ));
+        assert_eq!(result, Some(4321));
+    }
 }
@@ -1,30 +1,26 @@
-use std::collections::HashSet;
-
-use advent_of_code::{parse_ascii_map_ivec, Dir};
+use advent_of_code::{
+    bit_grid::BitGrid, grid::ascii_size, parse_ascii_map_ivec, template::Input, Dir,
+};
 use glam::{uvec2, UVec2};
+use itertools::Itertools;
 
 advent_of_code::solution!();
 
 /* -------------------------------------------------------------------------- */
 
-fn parse_one(
-    input: &str,
-) -> (
-    UVec2,
-    HashSet<UVec2>,
-    HashSet<UVec2>,
-    impl Iterator<Item = Dir> + '_,
-) {
+fn parse_one(input: &Input) -> (UVec2, BitGrid, BitGrid, impl Iterator<Item = Dir> + '_) {
     debug_assert!(input.is_ascii());
 
-    let (map, moves) = input.split_once("\n\n").unwrap();
+    let (map, moves) = input.blocks().collect_tuple().unwrap();
+
+    let (width, height) = ascii_size(map);
 
     let mut robot_position = None;
-    let mut box_positions = HashSet::new();
-    let mut walls = HashSet::new();
+    let mut box_positions = BitGrid::new(width, height);
+    let mut walls = BitGrid::new(width, height);
 
     parse_ascii_map_ivec(map).for_each(|(pos, c)| {
-        let pos = pos.try_into().unwrap();
+        let pos: UVec2 = pos.try_into().unwrap();
         match c {
             b'#' => {
                 walls.insert(pos);
@@ -44,20 +40,14 @@ fn parse_one(
     let moves = moves
         .lines()
         .flat_map(|line| line.bytes())
-        .map(|c| match c {
-            b'^' => Dir::Up,
-            b'>' => Dir::Right,
-            b'v' => Dir::Down,
-            b'<' => Dir::Left,
-            _ => unreachable!(),
-        });
+        .map(|c| Dir::from_ascii(c).unwrap());
 
     let robot_position = robot_position.unwrap();
 
     (robot_position, walls, box_positions, moves)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let (mut robot_position, walls, mut box_positions, moves) = parse_one(input);
 
     let mut box_to_move = Vec::new();
@@ -70,12 +60,12 @@ pub fn part_one(input: &str) -> Option<u32> {
             // `pos`` cannot be at left-most or top-most thanks to walls so it shouldn't actually wrap.
             pos = pos.wrapping_add_signed(dir);
 
-            if walls.contains(&pos) {
+            if walls.contains(pos) {
                 // We reach a wall, we cannot move
                 break false;
-            } else if let Some(box_pos) = box_positions.get(&pos).copied() {
+            } else if box_positions.contains(pos) {
                 // We found a box, wait to see if we can move it
-                box_to_move.push(box_pos);
+                box_to_move.push(pos);
             } else {
                 // We found a hole, we can move
                 break true;
@@ -85,7 +75,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         if can_move {
             robot_position = robot_position.wrapping_add_signed(dir);
             for box_pos in box_to_move.drain(..).rev() {
-                box_positions.remove(&box_pos);
+                box_positions.remove(box_pos);
                 box_positions.insert(box_pos.wrapping_add_signed(dir));
             }
         } else {
@@ -95,7 +85,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 
     Some(
         box_positions
-            .into_iter()
+            .iter::<UVec2>()
             .map(|pos| 100 * pos.y + pos.x)
             .sum(),
     )
@@ -103,21 +93,16 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 /* -------------------------------------------------------------------------- */
 
-fn parse_two(
-    input: &str,
-) -> (
-    UVec2,
-    HashSet<UVec2>,
-    HashSet<UVec2>,
-    impl Iterator<Item = Dir> + '_,
-) {
+fn parse_two(input: &Input) -> (UVec2, BitGrid, BitGrid, impl Iterator<Item = Dir> + '_) {
     debug_assert!(input.is_ascii());
 
-    let (map, moves) = input.split_once("\n\n").unwrap();
+    let (map, moves) = input.blocks().collect_tuple().unwrap();
+
+    let (width, height) = ascii_size(map);
 
     let mut robot_position = None;
-    let mut box_positions = HashSet::new();
-    let mut walls = HashSet::new();
+    let mut box_positions = BitGrid::new(2 * width, height);
+    let mut walls = BitGrid::new(2 * width, height);
 
     parse_ascii_map_ivec(map).for_each(|(pos, c)| {
         let mut pos: UVec2 = pos.try_into().unwrap();
@@ -143,20 +128,14 @@ fn parse_two(
     let moves = moves
         .lines()
         .flat_map(|line| line.bytes())
-        .map(|c| match c {
-            b'^' => Dir::Up,
-            b'>' => Dir::Right,
-            b'v' => Dir::Down,
-            b'<' => Dir::Left,
-            _ => unreachable!(),
-        });
+        .map(|c| Dir::from_ascii(c).unwrap());
 
     let robot_position = robot_position.unwrap();
 
     (robot_position, walls, box_positions, moves)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let (mut robot_position, walls, mut box_positions, moves) = parse_two(input);
 
     let mut box_to_move = Vec::new();
@@ -172,13 +151,13 @@ pub fn part_two(input: &str) -> Option<u32> {
             // `pos` cannot be at left-most or top-most thanks to walls so it shouldn't actually wrap.
             let pos = pos.wrapping_add_signed(movement);
 
-            if walls.contains(&pos) {
+            if walls.contains(pos) {
                 // We reach a wall, we cannot move
                 can_move = false;
                 break;
-            } else if let Some(box_pos) = box_positions.get(&pos).copied() {
+            } else if box_positions.contains(pos) {
                 // We found a box, wait to see if we can move it
-                box_to_move.push(box_pos);
+                box_to_move.push(pos);
 
                 if is_vertical {
                     pos_to_check.push(pos);
@@ -186,9 +165,9 @@ pub fn part_two(input: &str) -> Option<u32> {
                 } else {
                     pos_to_check.push(pos + uvec2(1, 0));
                 }
-            } else if let Some(box_pos) = box_positions.get(&(pos - uvec2(1, 0))).copied() {
+            } else if box_positions.contains(pos - uvec2(1, 0)) {
                 // We found a box, wait to see if we can move it
-                box_to_move.push(box_pos);
+                box_to_move.push(pos - uvec2(1, 0));
 
                 if is_vertical {
                     pos_to_check.push(pos);
@@ -205,7 +184,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             debug_assert!(pos_to_check.is_empty());
             robot_position = robot_position.wrapping_add_signed(movement);
             for box_pos in box_to_move.drain(..).rev() {
-                box_positions.remove(&box_pos);
+                box_positions.remove(box_pos);
                 box_positions.insert(box_pos.wrapping_add_signed(movement));
             }
         } else {
@@ -216,7 +195,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(
         box_positions
-            .into_iter()
+            .iter::<UVec2>()
             .map(|pos| 100 * pos.y + pos.x)
             .sum(),
     )
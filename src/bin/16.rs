@@ -1,6 +1,4 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
-
-use advent_of_code::{cmp_uvec2, parse_ascii_map_ivec, Dir};
+use advent_of_code::{bit_grid::BitGrid, parse_ascii_map_ivec, search, Dir};
 use glam::UVec2;
 
 advent_of_code::solution!();
@@ -8,21 +6,18 @@ advent_of_code::solution!();
 struct Input {
     start_position: UVec2,
     end_position: UVec2,
-    walls: HashSet<UVec2>,
+    walls: BitGrid,
 }
 
 fn parse(input: &str) -> Input {
     let mut start_position = None;
     let mut end_position = None;
-    let mut walls = HashSet::new();
+    let walls = BitGrid::from_ascii(input, |c| c == b'#');
 
     parse_ascii_map_ivec(input).for_each(|(pos, c)| {
         let pos = pos.try_into().unwrap();
 
         match c {
-            b'#' => {
-                walls.insert(pos);
-            }
             b'S' => {
                 debug_assert!(start_position.is_none());
                 start_position = Some(pos);
@@ -31,7 +26,7 @@ fn parse(input: &str) -> Input {
                 debug_assert!(end_position.is_none());
                 end_position = Some(pos);
             }
-            b'.' => { /* nothing */ }
+            b'#' | b'.' => { /* nothing */ }
             _ => unreachable!(),
         }
     });
@@ -43,295 +38,100 @@ fn parse(input: &str) -> Input {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let Input {
-        start_position,
-        end_position,
-        walls,
-    } = parse(input);
-
-    let mut explorer = Explorer::new();
-
-    explorer.new_state(
-        Pos {
-            position: start_position,
-            facing: Dir::East,
-        },
-        0,
-    );
-
-    while let Some(state) = explorer.states.pop_first() {
-        // move forward
-        {
-            let new_pos = state.pos.move_forward();
-            let new_score = state.score + 1;
-
-            if new_pos.position == end_position {
-                explorer.min_score = explorer.min_score.min(new_score);
-                // We reach the end other moves are useless
-                continue;
-            } else if !walls.contains(&new_pos.position) {
-                explorer.new_state(new_pos, new_score);
-            }
-        }
-
-        // rotate clockwise
-        {
-            let new_pos = state.pos.rotate_clockwise();
-            let new_score = state.score + 1000;
-            explorer.new_state(new_pos, new_score);
-        }
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct Pos {
+    position: UVec2,
+    facing: Dir,
+}
 
-        // rotate counterclockwise
-        {
-            let new_pos = state.pos.rotate_counterclockwise();
-            let new_score = state.score + 1000;
-            explorer.new_state(new_pos, new_score);
+impl Pos {
+    fn move_forward(self) -> Self {
+        let new_pos = self
+            .position
+            .wrapping_add_signed(self.facing.as_vec_down_right());
+        Self {
+            position: new_pos,
+            facing: self.facing,
         }
     }
 
-    return Some(explorer.min_score);
-
-    struct Explorer {
-        visited: HashMap<Pos, u32>,
-        states: BTreeSet<State>,
-        min_score: u32,
-    }
-
-    impl Explorer {
-        fn new() -> Self {
-            Self {
-                visited: HashMap::new(),
-                states: BTreeSet::new(),
-                min_score: u32::MAX,
-            }
-        }
-
-        fn new_state(&mut self, pos: Pos, score: u32) {
-            let should_add_new_state = if self.min_score <= score {
-                false
-            } else if let Some(&visited_score) = self.visited.get(&pos) {
-                score < visited_score
-            } else {
-                true
-            };
-
-            if should_add_new_state {
-                self.states.insert(State { pos, score });
-                self.visited.insert(pos, score);
-            }
+    fn rotate_clockwise(self) -> Self {
+        Self {
+            position: self.position,
+            facing: self.facing.rotated_clockwise(),
         }
     }
 
-    #[derive(Clone, Copy, Hash, PartialEq, Eq)]
-    struct Pos {
-        position: UVec2,
-        facing: Dir,
-    }
-
-    impl Pos {
-        fn move_forward(self) -> Self {
-            let new_pos = self
-                .position
-                .wrapping_add_signed(self.facing.as_vec_down_right());
-            Self {
-                position: new_pos,
-                facing: self.facing,
-            }
-        }
-
-        fn rotate_clockwise(self) -> Self {
-            Self {
-                position: self.position,
-                facing: self.facing.rotated_clockwise(),
-            }
+    fn rotate_counterclockwise(self) -> Self {
+        Self {
+            position: self.position,
+            facing: self.facing.rotated_anti_clockwise(),
         }
-
-        fn rotate_counterclockwise(self) -> Self {
-            Self {
-                position: self.position,
-                facing: self.facing.rotated_anti_clockwise(),
-            }
-        }
-    }
-
-    #[derive(PartialEq, Eq)]
-    struct State {
-        score: u32,
-        pos: Pos,
     }
 
-    impl PartialOrd for State {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            Some(self.cmp(other))
-        }
-    }
+    /// The reindeer can move forward for a score of 1, or rotate for a score of 1000.
+    fn successors(self, walls: &BitGrid) -> impl Iterator<Item = (Pos, u32)> {
+        let forward = self.move_forward();
+        let forward = (!walls.contains(forward.position)).then_some((forward, 1));
 
-    impl Ord for State {
-        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-            self.score
-                .cmp(&other.score)
-                .then(cmp_uvec2(&self.pos.position, &other.pos.position))
-                .then(self.pos.facing.cmp(&other.pos.facing))
-        }
+        forward.into_iter().chain([
+            (self.rotate_clockwise(), 1000),
+            (self.rotate_counterclockwise(), 1000),
+        ])
     }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<u32> {
     let Input {
         start_position,
         end_position,
         walls,
     } = parse(input);
 
-    let mut explorer = Explorer::new();
-
-    explorer.new_state(
-        Pos {
-            position: start_position,
-            facing: Dir::East,
-        },
-        0,
-        HashSet::new(),
-    );
-
-    // The best score (lowest) is at the end of `explorer.states`
-    while let Some(state) = explorer.states.pop() {
-        // move forward
-        {
-            let new_pos = state.pos.move_forward();
-            let new_score = state.score + 1;
+    let start = Pos {
+        position: start_position,
+        facing: Dir::East,
+    };
 
-            if new_pos.position == end_position {
-                match new_score.cmp(&explorer.min_score) {
-                    std::cmp::Ordering::Less => {
-                        explorer.part_of_min_path.clear();
-                        explorer.part_of_min_path.extend(state.visited);
-                        explorer.min_score = new_score;
-                    }
-                    std::cmp::Ordering::Equal => {
-                        explorer.part_of_min_path.extend(state.visited);
-                    }
-                    std::cmp::Ordering::Greater => {
-                        // nothing: the current path has a score bigger than the current min
-                    }
-                }
+    let (_, min_score) = search::dijkstra(
+        start,
+        |pos| pos.successors(&walls),
+        |pos| pos.position == end_position,
+    )?;
 
-                // We reach the end other moves are useless
-                continue;
-            } else if !walls.contains(&new_pos.position) {
-                explorer.new_state(new_pos, new_score, state.visited.clone());
-            }
-        }
-
-        // rotate clockwise
-        {
-            let new_pos = state.pos.rotate_clockwise();
-            let new_score = state.score + 1000;
-            explorer.new_state(new_pos, new_score, state.visited.clone());
-        }
-
-        // rotate counterclockwise
-        {
-            let new_pos = state.pos.rotate_counterclockwise();
-            let new_score = state.score + 1000;
-            explorer.new_state(new_pos, new_score, state.visited);
-        }
-    }
-
-    let part_of_min_path_count: u32 = explorer.part_of_min_path.len().try_into().unwrap();
-
-    // we add one for the end tile
-    return Some(part_of_min_path_count + 1);
-
-    struct Explorer {
-        visited: HashMap<Pos, u32>,
-
-        /// States to explore order by score.
-        /// The last item has the lowest score.
-        states: Vec<State>,
-
-        min_score: u32,
-        part_of_min_path: HashSet<UVec2>,
-    }
-
-    impl Explorer {
-        fn new() -> Self {
-            Self {
-                visited: HashMap::new(),
-                states: Vec::new(),
-                min_score: u32::MAX,
-                part_of_min_path: HashSet::new(),
-            }
-        }
+    Some(min_score)
+}
 
-        fn new_state(&mut self, pos: Pos, score: u32, mut old_visited: HashSet<UVec2>) {
-            let should_add_new_state = if self.min_score < score {
-                false
-            } else if let Some(&visited_score) = self.visited.get(&pos) {
-                score <= visited_score
-            } else {
-                true
-            };
+pub fn part_two(input: &str) -> Option<u32> {
+    let Input {
+        start_position,
+        end_position,
+        walls,
+    } = parse(input);
 
-            if should_add_new_state {
-                old_visited.insert(pos.position);
-                self.visited.insert(pos, score);
-                let insert_at = match self
-                    .states
-                    .binary_search_by(|item| item.score.cmp(&score).reverse())
-                {
-                    Ok(index) | Err(index) => index,
-                };
-                self.states.insert(
-                    insert_at,
-                    State {
-                        score,
-                        pos,
-                        visited: old_visited,
-                    },
-                );
-            }
-        }
-    }
+    let start = Pos {
+        position: start_position,
+        facing: Dir::East,
+    };
 
-    #[derive(Clone, Copy, Hash, PartialEq, Eq)]
-    struct Pos {
-        position: UVec2,
-        facing: Dir,
-    }
+    let paths = search::dijkstra_all([start], |pos| pos.successors(&walls));
 
-    impl Pos {
-        fn move_forward(self) -> Self {
-            let new_pos = self
-                .position
-                .wrapping_add_signed(self.facing.as_vec_down_right());
-            Self {
-                position: new_pos,
-                facing: self.facing,
-            }
-        }
+    let ends = Dir::ALL.map(|facing| Pos {
+        position: end_position,
+        facing,
+    });
 
-        fn rotate_clockwise(self) -> Self {
-            Self {
-                position: self.position,
-                facing: self.facing.rotated_clockwise(),
-            }
-        }
+    let min_score = ends.iter().filter_map(|end| paths.distance(end)).min()?;
 
-        fn rotate_counterclockwise(self) -> Self {
-            Self {
-                position: self.position,
-                facing: self.facing.rotated_anti_clockwise(),
-            }
-        }
-    }
+    let mut part_of_min_path = BitGrid::new(walls.width(), walls.height());
+    part_of_min_path.extend(
+        ends.iter()
+            .filter(|end| paths.distance(end) == Some(min_score))
+            .flat_map(|end| paths.states_on_paths_to(end))
+            .map(|pos| pos.position),
+    );
 
-    struct State {
-        score: u32,
-        pos: Pos,
-        visited: HashSet<UVec2>,
-    }
+    Some(part_of_min_path.len().try_into().unwrap())
 }
 
 #[cfg(test)]
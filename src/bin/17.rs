@@ -1,6 +1,7 @@
 use core::fmt::Write;
 use std::ops::ControlFlow;
 
+use advent_of_code::scan;
 use itertools::Itertools;
 
 advent_of_code::solution!(*, 1);
@@ -14,14 +15,11 @@ fn parse(input: &str) -> Input {
     debug_assert!(input.is_ascii());
     let (a, b, c, _, instructions) = input.lines().collect_tuple().unwrap();
 
-    const REGISTER_PREFIX_LEN: usize = "Register A: ".len();
-    const INSTRUCTION_PREFIX: &str = "Program: ";
+    let a = scan!(a, "Register A: {}" => u64).unwrap();
+    let b = scan!(b, "Register B: {}" => u64).unwrap();
+    let c = scan!(c, "Register C: {}" => u64).unwrap();
 
-    let a = a[REGISTER_PREFIX_LEN..].parse().unwrap();
-    let b = b[REGISTER_PREFIX_LEN..].parse().unwrap();
-    let c = c[REGISTER_PREFIX_LEN..].parse().unwrap();
-
-    let instructions = instructions.strip_prefix(INSTRUCTION_PREFIX).unwrap();
+    let instructions = scan!(instructions, "Program: {}" => String).unwrap();
 
     let instructions = (0..instructions.len())
         .step_by(2)
@@ -61,10 +59,7 @@ fn run(
     };
     let mut pc = 0;
 
-    loop {
-        let Some(&operand) = vm.instructions.get(pc + 1) else {
-            break;
-        };
+    while let Some(&operand) = vm.instructions.get(pc + 1) {
         let Some(&opcode) = vm.instructions.get(pc) else {
             break;
         };
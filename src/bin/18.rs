@@ -1,17 +1,10 @@
-use std::collections::{HashSet, VecDeque};
-
-use advent_of_code::four_directions_bounded;
+use advent_of_code::{bit_grid::BitGrid, four_directions_bounded, parse_lines, search};
 use glam::{uvec2, UVec2};
 
 advent_of_code::solution!();
 
 fn parse(input: &str) -> impl Iterator<Item = UVec2> + '_ {
-    input.lines().map(|line| {
-        let (x, y) = line.split_once(',').unwrap();
-        let x = x.parse().unwrap();
-        let y = y.parse().unwrap();
-        uvec2(x, y)
-    })
+    parse_lines(input).map(Result::unwrap)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -19,75 +12,39 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 fn solve_one(input: &str, bounds: UVec2, fallen_bytes: usize) -> u32 {
-    struct Explorer {
-        pos: UVec2,
-        distance: u32,
-    }
-
-    let mut visited = HashSet::new();
-    let mut to_explore = VecDeque::new();
-
-    to_explore.push_back(Explorer {
-        pos: uvec2(0, 0),
-        distance: 0,
-    });
-    visited.insert(uvec2(0, 0));
-
     let goal = bounds - uvec2(1, 1);
-    let walls: HashSet<_> = parse(input).take(fallen_bytes).collect();
-
-    while let Some(explorer) = to_explore.pop_front() {
-        for pos in four_directions_bounded(explorer.pos, bounds) {
-            if pos == goal {
-                return explorer.distance + 1;
-            }
-
-            if !walls.contains(&pos) && visited.insert(pos) {
-                to_explore.push_back(Explorer {
-                    pos,
-                    distance: explorer.distance + 1,
-                });
-            }
-        }
-    }
-
-    unreachable!()
+    let mut walls = BitGrid::new(bounds.x.try_into().unwrap(), bounds.y.try_into().unwrap());
+    walls.extend(parse(input).take(fallen_bytes));
+
+    let path = search::bfs(
+        uvec2(0, 0),
+        |&pos| four_directions_bounded(pos, bounds).filter(|pos| !walls.contains(*pos)),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+
+    // NOTE: the path contains the start position
+    (path.len() - 1).try_into().unwrap()
 }
 
 /* -------------------------------------------------------------------------- */
 
 fn solve_two(input: &str, bounds: UVec2) -> String {
-    let falling_bytes = parse(input);
-
-    let mut walls = HashSet::new();
-
-    let mut visited = HashSet::new();
-    let mut to_explore = Vec::new();
-
     let goal = bounds - uvec2(1, 1);
+    let mut walls = BitGrid::new(bounds.x.try_into().unwrap(), bounds.y.try_into().unwrap());
 
-    'falling_bytes: for byte in falling_bytes {
+    for byte in parse(input) {
         walls.insert(byte);
 
-        visited.clear();
-        to_explore.clear();
-
-        to_explore.push(uvec2(0, 0));
-        visited.insert(uvec2(0, 0));
-
-        while let Some(pos) = to_explore.pop() {
-            for pos in four_directions_bounded(pos, bounds) {
-                if pos == goal {
-                    continue 'falling_bytes;
-                }
+        let path = search::bfs(
+            uvec2(0, 0),
+            |&pos| four_directions_bounded(pos, bounds).filter(|pos| !walls.contains(*pos)),
+            |&pos| pos == goal,
+        );
 
-                if !walls.contains(&pos) && visited.insert(pos) {
-                    to_explore.push(pos);
-                }
-            }
+        if path.is_none() {
+            return format!("{},{}", byte.x, byte.y);
         }
-
-        return format!("{},{}", byte.x, byte.y);
     }
 
     unreachable!();
@@ -1,5 +1,4 @@
-use std::collections::HashMap;
-
+use advent_of_code::memo::Memo;
 use itertools::Itertools;
 
 advent_of_code::solution!();
@@ -52,38 +51,34 @@ pub fn part_two(input: &str) -> Option<u64> {
     let (towels, designs) = parse(input);
 
     let towels = towels.collect_vec();
-    let mut memory = HashMap::new();
+    let mut memo = Memo::new();
 
     let possible_design = designs
-        .map(|design| number_of_combination(&mut memory, design, &towels))
+        .map(|design| number_of_combination(&mut memo, design, &towels))
         .sum();
 
     return Some(possible_design);
 
     fn number_of_combination<'a>(
-        memory: &mut HashMap<&'a [u8], u64>,
+        memo: &mut Memo<&'a [u8], u64>,
         design: &'a [u8],
         towels: &[&[u8]],
     ) -> u64 {
-        if let Some(count) = memory.get(design).copied() {
-            return count;
-        }
+        memo.get_or_insert_with(design, |memo| {
+            let mut count = 0;
 
-        let mut count = 0;
-
-        for towel in towels {
-            if design.starts_with(towel) {
-                if towel.len() == design.len() {
-                    count += 1;
-                } else {
-                    count += number_of_combination(memory, &design[towel.len()..], towels);
+            for towel in towels {
+                if design.starts_with(towel) {
+                    if towel.len() == design.len() {
+                        count += 1;
+                    } else {
+                        count += number_of_combination(memo, &design[towel.len()..], towels);
+                    }
                 }
             }
-        }
-
-        memory.insert(design, count);
 
-        count
+            count
+        })
     }
 }
 
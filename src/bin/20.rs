@@ -1,6 +1,4 @@
-use std::{collections::HashSet, mem};
-
-use advent_of_code::{four_directions, parse_ascii_map, VecExt};
+use advent_of_code::{bit_grid::BitGrid, four_directions, parse_ascii_map, search, VecExt};
 use glam::UVec2;
 use itertools::Itertools;
 
@@ -11,19 +9,16 @@ advent_of_code::solution!();
 struct Input {
     start: UVec2,
     end: UVec2,
-    route: HashSet<UVec2>,
+    route: BitGrid,
 }
 
 fn parse(input: &str) -> Input {
     let mut start = None;
     let mut end = None;
-    let mut route = HashSet::new();
+    let route = BitGrid::from_ascii(input, |c| c != b'#');
 
     parse_ascii_map(input).for_each(|(pos, c)| {
         match c {
-            b'.' => {
-                route.insert(pos);
-            }
             b'S' => {
                 debug_assert!(start.is_none());
                 start = Some(pos);
@@ -32,7 +27,7 @@ fn parse(input: &str) -> Input {
                 debug_assert!(end.is_none());
                 end = Some(pos);
             }
-            b'#' => { /* nothing */ }
+            b'#' | b'.' => { /* nothing */ }
             _ => unreachable!(),
         }
     });
@@ -40,9 +35,6 @@ fn parse(input: &str) -> Input {
     let start = start.unwrap();
     let end = end.unwrap();
 
-    route.insert(start);
-    route.insert(end);
-
     Input { start, end, route }
 }
 
@@ -51,36 +43,19 @@ fn parse(input: &str) -> Input {
 fn solve_one(input: &str, mut f: impl FnMut(usize)) {
     let Input { start, end, route } = parse(input);
 
-    let path = {
-        let mut path = Vec::with_capacity(route.len());
-
-        let mut previous: UVec2 = start;
-        let mut current = start;
-        path.push(start);
-
-        while current != end {
-            let mut positions = {
-                let route = &route;
-                four_directions(current).filter(move |p| route.contains(p) && *p != previous)
-            };
-
-            previous = mem::replace(&mut current, positions.next().unwrap());
-
-            // We assumes there is only one path
-            debug_assert!(positions.next().is_none());
-
-            path.push(current);
-        }
-
-        path
-    };
+    let path = search::bfs(
+        start,
+        |&pos| four_directions(pos).filter(|p| route.contains(*p)),
+        |&pos| pos == end,
+    )
+    .unwrap();
 
     for (time, pos) in path[..path.len() - 1].iter().copied().enumerate() {
         // up
         {
             if let Some(wall_pos) = pos.up() {
                 if let Some(route_pos) = wall_pos.up() {
-                    if !route.contains(&wall_pos) {
+                    if !route.contains(wall_pos) {
                         if let Some((target_time, _)) = path[time + 1..]
                             .iter()
                             .copied()
@@ -98,7 +73,7 @@ fn solve_one(input: &str, mut f: impl FnMut(usize)) {
         {
             if let Some(wall_pos) = pos.left() {
                 if let Some(route_pos) = wall_pos.left() {
-                    if !route.contains(&wall_pos) {
+                    if !route.contains(wall_pos) {
                         if let Some((target_time, _)) = path[time + 1..]
                             .iter()
                             .copied()
@@ -118,7 +93,7 @@ fn solve_one(input: &str, mut f: impl FnMut(usize)) {
             let wall_pos = pos.down();
             let route_pos = wall_pos.down();
 
-            if !route.contains(&wall_pos) {
+            if !route.contains(wall_pos) {
                 if let Some((target_time, _)) = path[time + 1..]
                     .iter()
                     .copied()
@@ -136,7 +111,7 @@ fn solve_one(input: &str, mut f: impl FnMut(usize)) {
             let wall_pos = pos.right();
             let route_pos = wall_pos.right();
 
-            if !route.contains(&wall_pos) {
+            if !route.contains(wall_pos) {
                 if let Some((target_time, _)) = path[time + 1..]
                     .iter()
                     .copied()
@@ -168,29 +143,12 @@ pub fn part_one(input: &str) -> Option<u32> {
 fn solve_two(input: &str, mut f: impl FnMut(usize)) {
     let Input { start, end, route } = parse(input);
 
-    let path = {
-        let mut path = Vec::with_capacity(route.len());
-
-        let mut previous: UVec2 = start;
-        let mut current = start;
-        path.push(start);
-
-        while current != end {
-            let mut positions = {
-                let route = &route;
-                four_directions(current).filter(move |p| route.contains(p) && *p != previous)
-            };
-
-            previous = mem::replace(&mut current, positions.next().unwrap());
-
-            // We assumes there is only one path
-            debug_assert!(positions.next().is_none());
-
-            path.push(current);
-        }
-
-        path
-    };
+    let path = search::bfs(
+        start,
+        |&pos| four_directions(pos).filter(|p| route.contains(*p)),
+        |&pos| pos == end,
+    )
+    .unwrap();
 
     for (time, a) in path.iter().copied().enumerate() {
         for (time2, b) in path[time + 1..].iter().copied().enumerate() {
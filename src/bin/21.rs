@@ -1,6 +1,6 @@
 #![allow(dead_code, unused_variables)]
 
-use advent_of_code::{Dir, Horizontal, Vertical};
+use advent_of_code::{Dir, Dir8};
 use glam::{uvec2, UVec2};
 
 advent_of_code::solution!();
@@ -123,31 +123,19 @@ fn compute_sequence_for_numerical_keyboard(
     for goal in sequence {
         let pos = goal.pos();
 
-        let horizontal = match current_position.x.cmp(&pos.x) {
-            std::cmp::Ordering::Less => Some((Horizontal::Right, pos.x - current_position.x)),
-            std::cmp::Ordering::Equal => None,
-            std::cmp::Ordering::Greater => Some((Horizontal::Left, current_position.x - pos.x)),
-        };
-
-        let vertical = match current_position.y.cmp(&pos.y) {
-            std::cmp::Ordering::Less => Some((Vertical::Down, pos.y - current_position.y)),
-            std::cmp::Ordering::Equal => None,
-            std::cmp::Ordering::Greater => Some((Vertical::Up, current_position.y - pos.y)),
-        };
-
-        let movement = match (horizontal, vertical) {
-            (None, None) => Movement::NoMove,
-            (None, Some((dir, distance))) => Movement::OneDirection {
-                dir: dir.into(),
-                distance,
+        let delta = pos.as_ivec2() - current_position.as_ivec2();
+
+        let movement = match Dir8::from_vec_down_right(delta) {
+            None => Movement::NoMove,
+            Some(dir) => match dir.as_dir() {
+                Some(dir) => Movement::OneDirection {
+                    dir,
+                    distance: delta.abs().max_element().try_into().unwrap(),
+                },
+                None => {
+                    todo!();
+                }
             },
-            (Some((dir, distance)), None) => Movement::OneDirection {
-                dir: dir.into(),
-                distance,
-            },
-            (Some((horizontal, h_distance)), Some((vertical, v_distance))) => {
-                todo!();
-            }
         };
 
         nodes.push(Node {
@@ -0,0 +1,227 @@
+use crate::grid::{self, GridPos};
+use crate::MapSize;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of positions in a 2D grid, stored as one bit per cell.
+///
+/// Positions can be any [`GridPos`], those out of the grid are never contained.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl BitGrid {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            words: vec![0; (width * height).div_ceil(WORD_BITS)],
+            width,
+            height,
+        }
+    }
+
+    /// Parses an ascii map, the cells for which `predicate` returns `true` are in the set.
+    pub fn from_ascii(input: &str, mut predicate: impl FnMut(u8) -> bool) -> Self {
+        let (width, height) = grid::ascii_size(input);
+        let mut set = Self::new(width, height);
+        for (pos, c) in grid::ascii_cells::<(usize, usize)>(input) {
+            if predicate(c) {
+                set.insert(pos);
+            }
+        }
+        set
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    pub fn map_size(&self) -> MapSize {
+        MapSize::new(
+            self.width.try_into().unwrap(),
+            self.height.try_into().unwrap(),
+        )
+    }
+
+    /// Returns the index of the bit of `pos`, or [`None`] if it is out of the grid.
+    #[inline]
+    fn bit_index<P: GridPos>(&self, pos: P) -> Option<usize> {
+        let (row, column) = pos.to_row_column()?;
+        (row < self.height && column < self.width).then_some(row * self.width + column)
+    }
+
+    #[inline]
+    pub fn contains<P: GridPos>(&self, pos: P) -> bool {
+        self.bit_index(pos)
+            .is_some_and(|i| self.words[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0)
+    }
+
+    /// Adds `pos` to the set.
+    ///
+    /// Returns whether the position was newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of the grid.
+    #[inline]
+    pub fn insert<P: GridPos>(&mut self, pos: P) -> bool {
+        let i = self.bit_index(pos).expect("position out of the grid");
+        let word = &mut self.words[i / WORD_BITS];
+        let mask = 1 << (i % WORD_BITS);
+        let is_new = *word & mask == 0;
+        *word |= mask;
+        is_new
+    }
+
+    /// Removes `pos` from the set.
+    ///
+    /// Returns whether the position was present in the set.
+    #[inline]
+    pub fn remove<P: GridPos>(&mut self, pos: P) -> bool {
+        let Some(i) = self.bit_index(pos) else {
+            return false;
+        };
+        let word = &mut self.words[i / WORD_BITS];
+        let mask = 1 << (i % WORD_BITS);
+        let was_present = *word & mask != 0;
+        *word &= !mask;
+        was_present
+    }
+
+    /// Inserts or removes `pos` depending on `value`.
+    #[inline]
+    pub fn set<P: GridPos>(&mut self, pos: P, value: bool) {
+        if value {
+            self.insert(pos);
+        } else {
+            self.remove(pos);
+        }
+    }
+
+    /// Removes all the positions, keeping the size of the grid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Returns the number of positions in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let count: u64 = self.words.iter().map(|w| u64::from(w.count_ones())).sum();
+        count.try_into().unwrap()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Iterates over the positions in the set, row by row.
+    pub fn iter<P: GridPos>(&self) -> impl Iterator<Item = P> + '_ {
+        let width = self.width;
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit: usize = word.trailing_zeros().try_into().unwrap();
+                    word &= word - 1;
+                    Some(i * WORD_BITS + bit)
+                })
+            })
+            .map(move |i| P::from_row_column(i / width, i % width))
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    #[inline]
+    fn zip_words_with(&mut self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "grids haven't the same size"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    /// Adds the positions of `other` to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids haven't the same size.
+    #[inline]
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a | b);
+    }
+
+    /// Keeps only the positions that are also in `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids haven't the same size.
+    #[inline]
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a & b);
+    }
+
+    /// Removes the positions that are in `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids haven't the same size.
+    #[inline]
+    pub fn difference_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a & !b);
+    }
+
+    /// Keeps the positions that are in exactly one of the sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grids haven't the same size.
+    #[inline]
+    pub fn symmetric_difference_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a ^ b);
+    }
+
+    #[inline]
+    pub fn is_subset(&self, other: &BitGrid) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(&a, &b)| a & !b == 0)
+    }
+
+    #[inline]
+    pub fn is_disjoint(&self, other: &BitGrid) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(&a, &b)| a & b == 0)
+    }
+}
+
+impl<P: GridPos> Extend<P> for BitGrid {
+    #[inline]
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for pos in iter {
+            self.insert(pos);
+        }
+    }
+}
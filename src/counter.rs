@@ -0,0 +1,282 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use std::collections::HashMap;
+
+/// A multiset: the number of occurrences of each key.
+///
+/// Keys with a count of zero are never stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Hash + Eq> {
+    counts: HashMap<K, u64>,
+}
+
+impl<K: Hash + Eq> Default for Counter<K> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Counter<K> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct keys.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The sum of the counts of all the keys.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Returns the count of `key`, `0` if it isn't in the counter.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Adds `count` occurrences of `key`.
+    #[inline]
+    pub fn add_count(&mut self, key: K, count: u64) {
+        if count > 0 {
+            *self.counts.entry(key).or_insert(0) += count;
+        }
+    }
+
+    /// Removes `count` occurrences of `key`, the count doesn't go below zero.
+    #[inline]
+    pub fn sub_count<Q>(&mut self, key: &Q, count: u64)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(current) = self.counts.get_mut(key) {
+            *current = current.saturating_sub(count);
+            if *current == 0 {
+                self.counts.remove(key);
+            }
+        }
+    }
+
+    /// Removes all the occurrences of `key`, returning their count.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.counts.remove(key).unwrap_or(0)
+    }
+
+    /// Iterates over the keys with their count, in an arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, u64)> + Clone + '_ {
+        self.counts.iter().map(|(key, &count)| (key, count))
+    }
+
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + Clone + '_ {
+        self.counts.keys()
+    }
+
+    /// Returns the keys with their count, from the most common to the least common.
+    ///
+    /// The keys with the same count are in an arbitrary order.
+    pub fn most_common(&self) -> Vec<(&K, u64)> {
+        let mut counts = self.iter().collect::<Vec<_>>();
+        counts.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+        counts
+    }
+
+    /// Maps each key with `f`, the counts of the keys mapped to the same key are summed.
+    pub fn map_keys<U: Hash + Eq>(self, mut f: impl FnMut(K) -> U) -> Counter<U> {
+        let mut counter = Counter::new();
+        for (key, count) in self.counts {
+            counter.add_count(f(key), count);
+        }
+        counter
+    }
+
+    /// Maps each key to several ones with `f`, each of them gets the count of the original key.
+    ///
+    /// The counts of the keys mapped to the same key are summed.
+    pub fn flat_map_keys<U: Hash + Eq, I: IntoIterator<Item = U>>(
+        self,
+        mut f: impl FnMut(K) -> I,
+    ) -> Counter<U> {
+        let mut counter = Counter::new();
+        for (key, count) in self.counts {
+            for key in f(key) {
+                counter.add_count(key, count);
+            }
+        }
+        counter
+    }
+}
+
+impl<K: Hash + Eq> FromIterator<K> for Counter<K> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K: Hash + Eq> Extend<K> for Counter<K> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add_count(key, 1);
+        }
+    }
+}
+
+impl<K: Hash + Eq> AddAssign for Counter<K> {
+    /// Adds the counts of `rhs`.
+    fn add_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            self.add_count(key, count);
+        }
+    }
+}
+
+impl<K: Hash + Eq> SubAssign for Counter<K> {
+    /// Removes the counts of `rhs`, the counts don't go below zero.
+    fn sub_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            self.sub_count(&key, count);
+        }
+    }
+}
+
+impl<K: Hash + Eq> Add for Counter<K> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<K: Hash + Eq> Sub for Counter<K> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn counts_the_items() {
+        let counter = "abracadabra".chars().collect::<Counter<_>>();
+
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'b'), 2);
+        assert_eq!(counter.get(&'d'), 1);
+        assert_eq!(counter.get(&'z'), 0);
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.total(), 11);
+    }
+
+    #[test]
+    fn adds_and_subtracts_counts() {
+        let mut counter = Counter::new();
+        counter.add_count("x", 3);
+        counter.add_count("y", 0);
+        assert_eq!(counter.get("x"), 3);
+        assert_eq!(counter.len(), 1, "a zero count isn't stored");
+
+        counter.sub_count("x", 1);
+        assert_eq!(counter.get("x"), 2);
+
+        // Saturates at zero and removes the key.
+        counter.sub_count("x", 5);
+        assert_eq!(counter.get("x"), 0);
+        assert!(counter.is_empty());
+
+        counter.sub_count("missing", 1);
+        assert!(counter.is_empty());
+    }
+
+    #[test]
+    fn adds_and_subtracts_counters() {
+        let a = "aab".chars().collect::<Counter<_>>();
+        let b = "abbc".chars().collect::<Counter<_>>();
+
+        let sum = a.clone() + b.clone();
+        assert_eq!(sum.get(&'a'), 3);
+        assert_eq!(sum.get(&'b'), 3);
+        assert_eq!(sum.get(&'c'), 1);
+
+        let difference = a - b;
+        assert_eq!(difference.get(&'a'), 1);
+        assert_eq!(difference.get(&'b'), 0);
+        assert_eq!(difference.len(), 1);
+    }
+
+    #[test]
+    fn sorts_by_count() {
+        let counter = "aaabbbccd".chars().collect::<Counter<_>>();
+        let most_common = counter.most_common();
+
+        assert_eq!(
+            most_common
+                .iter()
+                .map(|&(_, count)| count)
+                .collect::<Vec<_>>(),
+            [3, 3, 2, 1]
+        );
+
+        // The tied keys come first, in any order.
+        let mut tied = most_common[..2]
+            .iter()
+            .map(|&(&c, _)| c)
+            .collect::<Vec<_>>();
+        tied.sort_unstable();
+        assert_eq!(tied, ['a', 'b']);
+        assert_eq!(most_common[2..], [(&'c', 2), (&'d', 1)]);
+    }
+
+    #[test]
+    fn merges_the_counts_of_mapped_keys() {
+        let counter = [1, 2, 3, 4, 5, 5].into_iter().collect::<Counter<i32>>();
+
+        let parity = counter.clone().map_keys(|n| n % 2);
+        assert_eq!(parity.get(&0), 2);
+        assert_eq!(parity.get(&1), 4);
+        assert_eq!(parity.len(), 2);
+
+        let digits = counter.flat_map_keys(|n| [n, n * 10]);
+        assert_eq!(digits.get(&5), 2);
+        assert_eq!(digits.get(&50), 2);
+        assert_eq!(digits.total(), 12);
+    }
+}
@@ -0,0 +1,219 @@
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// The cycle of a sequence `x0, step(x0), step(step(x0)), …`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle<S> {
+    /// The index of the first state of the cycle.
+    pub start: usize,
+    /// The number of states in the cycle, at least 1.
+    pub length: usize,
+    /// The first state of the cycle.
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// Returns the smallest index which has the same state as index `n`.
+    #[inline]
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare algorithm, in constant memory.
+///
+/// The sequence must be infinite, a terminal state can be modelled
+/// as a state that steps to itself.
+pub fn floyd<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    // Find a meeting point at an index multiple of the length.
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // Both are now `start` steps away from the start of the cycle.
+    let mut cycle_start = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+        state: tortoise,
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, in constant memory.
+///
+/// It calls `step` less often than [`floyd`].
+/// The sequence must be infinite, a terminal state can be modelled
+/// as a state that steps to itself.
+pub fn brent<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    // Find the length by teleporting the tortoise at each power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Move the hare `length` steps ahead, they then meet at the start of the cycle.
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+        state: tortoise,
+    }
+}
+
+/// Finds the cycle by remembering all the states, each state is computed only once.
+///
+/// Returns the cycle and the states before its end, indexed by their position in the sequence.
+pub fn find_cycle<S: Hash + Eq + Clone>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+) -> (Cycle<S>, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&cycle_start) = seen.get(&state) {
+            let cycle = Cycle {
+                start: cycle_start,
+                length: states.len() - cycle_start,
+                state,
+            };
+            return (cycle, states);
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Returns the state at index `n` of the sequence, skipping the repetitions of its cycle.
+pub fn nth_state<S: Hash + Eq + Clone>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    for i in 0..n {
+        if let Some(&cycle_start) = seen.get(&state) {
+            let cycle = Cycle {
+                start: cycle_start,
+                length: i - cycle_start,
+                state,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+
+    state
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, find_cycle, floyd, nth_state, Cycle};
+
+    /// A rho-shaped sequence: a tail of a few states, then a cycle.
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    fn brute_force(start: u32, step: impl Fn(&u32) -> u32, n: usize) -> u32 {
+        (0..n).fold(start, |x, _| step(&x))
+    }
+
+    #[test]
+    fn finds_the_same_cycle() {
+        let (cycle, states) = find_cycle(3, step);
+
+        assert!(cycle.start > 0, "the sequence has a tail");
+        assert!(cycle.length > 1);
+        assert_eq!(states.len(), cycle.start + cycle.length);
+        assert_eq!(cycle.state, brute_force(3, step, cycle.start));
+        assert_eq!(
+            brute_force(3, step, cycle.start + cycle.length),
+            cycle.state
+        );
+        assert_eq!(floyd(3, step), cycle);
+        assert_eq!(brent(3, step), cycle);
+    }
+
+    #[test]
+    fn finds_a_fixed_point() {
+        // 0, 10, 20, …, 100, 100, …
+        let step = |&x: &u32| (x + 10).min(100);
+        let expected = Cycle {
+            start: 10,
+            length: 1,
+            state: 100,
+        };
+
+        assert_eq!(find_cycle(0, step).0, expected);
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(nth_state(0, step, 1_000_000_000_000), 100);
+    }
+
+    #[test]
+    fn matches_the_brute_force_states() {
+        let (cycle, _) = find_cycle(3, step);
+
+        // Before the cycle, in its first lap and in the next ones.
+        for n in 0..cycle.start + 3 * cycle.length {
+            assert_eq!(nth_state(3, step, n), brute_force(3, step, n), "n = {n}");
+        }
+
+        let n = 1_000_000_000_000;
+        let reduced = cycle.start + (n - cycle.start) % cycle.length;
+        assert_eq!(nth_state(3, step, n), brute_force(3, step, reduced));
+    }
+}
@@ -0,0 +1,234 @@
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use glam::{uvec2, IVec2, UVec2};
+
+use crate::array2d::{self, Array2D};
+use crate::MapSize;
+
+/// A position in a [`Grid`].
+///
+/// Tuples are `(row, column)`, like the indices of [`Array2D`].
+/// Vectors are `(x, y)` where the positive directions are right for X axis and down for Y axis.
+pub trait GridPos: Copy {
+    /// Returns the `(row, column)` of the position, or [`None`] if it cannot be one.
+    fn to_row_column(self) -> Option<(usize, usize)>;
+
+    /// Creates the position from a `(row, column)`.
+    fn from_row_column(row: usize, column: usize) -> Self;
+}
+
+impl GridPos for (usize, usize) {
+    #[inline]
+    fn to_row_column(self) -> Option<(usize, usize)> {
+        Some(self)
+    }
+
+    #[inline]
+    fn from_row_column(row: usize, column: usize) -> Self {
+        (row, column)
+    }
+}
+
+impl GridPos for UVec2 {
+    #[inline]
+    fn to_row_column(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    #[inline]
+    fn from_row_column(row: usize, column: usize) -> Self {
+        uvec2(column.try_into().unwrap(), row.try_into().unwrap())
+    }
+}
+
+impl GridPos for IVec2 {
+    #[inline]
+    fn to_row_column(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    #[inline]
+    fn from_row_column(row: usize, column: usize) -> Self {
+        glam::ivec2(column.try_into().unwrap(), row.try_into().unwrap())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A 2D grid that can be accessed with any [`GridPos`].
+///
+/// The grid derefs to [`Array2D`], for the `(row, column)` based API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    array: Array2D<T>,
+}
+
+impl<T> Grid<T> {
+    #[inline]
+    pub fn from_elem<P: GridPos>(width: usize, height: usize, mut f: impl FnMut(P) -> T) -> Self {
+        let size = array2d::Size::from_rows_columns(height, width);
+        Self {
+            array: Array2D::from_elem(size, |row, column| f(P::from_row_column(row, column))),
+        }
+    }
+
+    /// Parses an ascii map, mapping each char with `f`.
+    #[inline]
+    pub fn parse_ascii(input: &str, f: impl FnMut(u8) -> T) -> Self {
+        let (width, height) = ascii_size(input);
+        let mut items = input.lines().flat_map(|line| line.bytes()).map(f);
+        Self::from_elem(width, height, |_: (usize, usize)| items.next().unwrap())
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.array.size().columns_count()
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.array.size().rows_count()
+    }
+
+    #[inline]
+    pub fn map_size(&self) -> MapSize {
+        MapSize::new(
+            self.width().try_into().unwrap(),
+            self.height().try_into().unwrap(),
+        )
+    }
+
+    #[inline]
+    pub fn contains<P: GridPos>(&self, pos: P) -> bool {
+        pos.to_row_column()
+            .is_some_and(|(row, column)| row < self.height() && column < self.width())
+    }
+
+    #[inline]
+    pub fn get<P: GridPos>(&self, pos: P) -> Option<&T> {
+        let (row, column) = pos.to_row_column()?;
+        self.array.get(row, column)
+    }
+
+    #[inline]
+    pub fn get_mut<P: GridPos>(&mut self, pos: P) -> Option<&mut T> {
+        let (row, column) = pos.to_row_column()?;
+        self.array.get_mut(row, column)
+    }
+
+    /// Iterates over the positions of the grid, row by row.
+    #[inline]
+    pub fn positions<P: GridPos>(&self) -> impl DoubleEndedIterator<Item = P> + Clone + '_ {
+        self.array
+            .iter_with_index()
+            .map(|((row, column), _)| P::from_row_column(row, column))
+    }
+
+    /// Iterates over the items of the grid with their positions, row by row.
+    #[inline]
+    pub fn iter_with_pos<P: GridPos>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (P, &T)> + Clone + '_ {
+        self.array
+            .iter_with_index()
+            .map(|((row, column), item)| (P::from_row_column(row, column), item))
+    }
+
+    /// Iterates over the items of the grid with their positions, row by row.
+    #[inline]
+    pub fn iter_mut_with_pos<P: GridPos>(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (P, &mut T)> + '_ {
+        self.array
+            .iter_mut_with_index()
+            .map(|((row, column), item)| (P::from_row_column(row, column), item))
+    }
+
+    #[inline]
+    pub fn into_array2d(self) -> Array2D<T> {
+        self.array
+    }
+}
+
+impl Grid<u8> {
+    /// Parses an ascii map.
+    #[inline]
+    pub fn from_ascii(input: &str) -> Self {
+        Self::parse_ascii(input, |x| x)
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = Array2D<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.array
+    }
+}
+
+impl<T> From<Array2D<T>> for Grid<T> {
+    #[inline]
+    fn from(array: Array2D<T>) -> Self {
+        Self { array }
+    }
+}
+
+impl<T> From<Grid<T>> for Array2D<T> {
+    #[inline]
+    fn from(grid: Grid<T>) -> Self {
+        grid.array
+    }
+}
+
+impl<T, P: GridPos> Index<P> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, pos: P) -> &Self::Output {
+        self.get(pos).expect("position out of the grid")
+    }
+}
+
+impl<T, P: GridPos> IndexMut<P> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, pos: P) -> &mut Self::Output {
+        self.get_mut(pos).expect("position out of the grid")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the `(width, height)` of an ascii map.
+#[inline]
+pub fn ascii_size(input: &str) -> (usize, usize) {
+    debug_assert!(input.is_ascii());
+
+    let height = input.lines().count();
+    let width = input.lines().next().unwrap().len();
+
+    debug_assert!(
+        input.lines().all(|line| line.len() == width),
+        "some lines of `input` haven't the same length"
+    );
+
+    (width, height)
+}
+
+/// Iterates over the chars of an ascii map with their positions.
+#[inline]
+pub fn ascii_cells<P: GridPos>(input: &str) -> impl Iterator<Item = (P, u8)> + Clone + '_ {
+    debug_assert!(input.is_ascii());
+    input.lines().enumerate().flat_map(|(row, line)| {
+        line.bytes()
+            .enumerate()
+            .map(move |(column, b)| (P::from_row_column(row, column), b))
+    })
+}
@@ -0,0 +1,188 @@
+//! Fast parsing of integers from ascii bytes, without UTF-8 validation.
+
+use core::fmt::{self, Display};
+use core::marker::PhantomData;
+
+/// An integer type that can be parsed from ascii digits.
+pub trait Int: Copy + Default {
+    /// Whether a `-` before the digits is part of the integer.
+    const SIGNED: bool;
+
+    /// Parses the base 10 `digits`, or returns [`None`] on overflow.
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($signed:literal => $($T:ty)*) => {
+        $(
+            impl Int for $T {
+                const SIGNED: bool = $signed;
+
+                #[inline]
+                fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+                    // Negative values are accumulated below zero so that `MIN` can be parsed.
+                    digits.iter().try_fold(0, |acc: Self, &d| {
+                        let d = Self::try_from(d - b'0').ok()?;
+                        let acc = acc.checked_mul(10)?;
+                        if negative {
+                            acc.checked_sub(d)
+                        } else {
+                            acc.checked_add(d)
+                        }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(false => u8 u16 u32 u64 u128 usize);
+impl_int!(true => i8 i16 i32 i64 i128 isize);
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses all of `bytes` as an integer with an optional sign.
+///
+/// Returns [`None`] if there are other characters than digits, or on overflow.
+#[inline]
+pub fn parse<T: Int>(bytes: &[u8]) -> Option<T> {
+    let (negative, digits) = match bytes {
+        [b'-', digits @ ..] if T::SIGNED => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    T::from_digits(negative, digits)
+}
+
+/// Iterates over all the integers of `line`, ignoring the other characters.
+///
+/// For signed types, a `-` right before the digits makes the integer negative.
+/// An integer that overflows `T` is yielded as an [`OverflowError`], the next ones still are.
+#[inline]
+pub fn iter<T: Int>(line: &(impl AsRef<[u8]> + ?Sized)) -> Ints<'_, T> {
+    Ints {
+        bytes: line.as_ref(),
+        _marker: PhantomData,
+    }
+}
+
+/// Returns the first `N` integers of `line`, or [`None`] if there are fewer
+/// or if one of them overflows `T`, like [`parse`].
+///
+/// e.g. `ints::<u32, 2>("x=12, y=34")` returns `Some([12, 34])`.
+#[inline]
+pub fn ints<T: Int, const N: usize>(line: &(impl AsRef<[u8]> + ?Sized)) -> Option<[T; N]> {
+    let mut ints = iter(line);
+    let mut array = [T::default(); N];
+    for value in &mut array {
+        *value = ints.next()?.ok()?;
+    }
+    Some(array)
+}
+
+/// The error yielded by [`iter`] for an integer that doesn't fit in the type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// The integer, with its sign.
+    pub int: String,
+}
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "integer `{}` overflows", self.int)
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// The iterator returned by [`iter`].
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = Result<T, OverflowError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(start) = self.bytes.iter().position(u8::is_ascii_digit) else {
+            self.bytes = &[];
+            return None;
+        };
+
+        let negative = T::SIGNED && start > 0 && self.bytes[start - 1] == b'-';
+
+        let bytes = &self.bytes[start..];
+        let end = bytes
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(bytes.len());
+        let (digits, rest) = bytes.split_at(end);
+        self.bytes = rest;
+
+        Some(T::from_digits(negative, digits).ok_or_else(|| {
+            let sign = if negative { "-" } else { "" };
+            OverflowError {
+                int: format!("{sign}{}", String::from_utf8_lossy(digits)),
+            }
+        }))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{ints, iter, parse, OverflowError};
+
+    #[test]
+    fn iterates_over_integers() {
+        let values = iter::<i32>("x=12, y=-34 z=+5").collect::<Result<Vec<_>, _>>();
+        assert_eq!(values, Ok(vec![12, -34, 5]));
+
+        let values = iter::<u32>("a-1b2").collect::<Result<Vec<_>, _>>();
+        assert_eq!(values, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let values = iter::<u8>("1 256 -3 300").collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                Ok(1),
+                Err(OverflowError { int: "256".into() }),
+                Ok(3),
+                Err(OverflowError { int: "300".into() }),
+            ]
+        );
+
+        let values = iter::<i8>("-128 -129").collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [Ok(-128), Err(OverflowError { int: "-129".into() })]
+        );
+        assert_eq!(
+            values[1].as_ref().unwrap_err().to_string(),
+            "integer `-129` overflows"
+        );
+    }
+
+    #[test]
+    fn parses_arrays_and_single_integers() {
+        assert_eq!(ints::<u32, 2>("x=12, y=34, z=56"), Some([12, 34]));
+        assert_eq!(ints::<u32, 3>("x=12, y=34"), None);
+        assert_eq!(ints::<u8, 2>("1 1000"), None);
+
+        assert_eq!(parse::<i64>(b"-42"), Some(-42));
+        assert_eq!(parse::<u64>(b"-42"), None);
+        assert_eq!(parse::<u8>(b"256"), None);
+    }
+}
@@ -3,20 +3,41 @@ pub mod template;
 // Use this file to add helper functions and additional modules.
 
 pub mod array2d;
+pub mod bit_grid;
+pub mod counter;
+pub mod cycle;
+pub mod grid;
+pub mod ints;
+pub mod linear;
+pub mod math;
+pub mod memo;
 pub mod points;
+pub mod range_set;
+pub mod region;
+pub mod scan;
+pub mod search;
+pub mod sparse_grid;
+pub mod union_find;
 
 /* -------------------------------------------------------------------------- */
 
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 use array2d::Array2D;
+use glam::{ivec2, uvec2, IVec2, UVec2};
 pub use glam::{ivec2 as pos, IVec2 as Pos};
-use glam::{uvec2, UVec2};
+use grid::Grid;
+use num::Integer;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MapSize(glam::IVec2);
 
 impl MapSize {
+    pub fn new(width: i32, height: i32) -> Self {
+        Self(glam::ivec2(width, height))
+    }
+
     pub fn width(&self) -> i32 {
         self.0.x
     }
@@ -30,65 +51,104 @@ impl MapSize {
     }
 }
 
-#[inline]
-pub fn ascii_map_size(input: &str) -> MapSize {
-    debug_assert!(input.is_ascii());
+/// The size of a map whose edges wrap around, going out on one side comes in on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus(glam::IVec2);
 
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().len();
+impl Torus {
+    pub fn new(width: i32, height: i32) -> Self {
+        assert!(width > 0 && height > 0, "the size must be positive");
+        Self(glam::ivec2(width, height))
+    }
 
-    debug_assert!(
-        input.lines().all(|line| line.len() == width),
-        "some lines of `input` haven't the same length"
-    );
+    pub fn width(&self) -> i32 {
+        self.0.x
+    }
 
-    MapSize(glam::ivec2(
-        width.try_into().unwrap(),
-        height.try_into().unwrap(),
-    ))
-}
+    pub fn height(&self) -> i32 {
+        self.0.y
+    }
 
-#[inline]
-pub fn ascii_map_size_uvec2(input: &str) -> UVec2 {
-    debug_assert!(input.is_ascii());
+    pub fn size(&self) -> IVec2 {
+        self.0
+    }
+
+    /// Returns the position in the map equivalent to `pos`.
+    #[inline]
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        pos.rem_euclid(self.0)
+    }
+
+    /// Returns the position reached from `pos` after moving at `velocity` for `time`.
+    #[inline]
+    pub fn advance(&self, pos: Pos, velocity: IVec2, time: i32) -> Pos {
+        // Computed in i64 to not overflow on long times.
+        let axis = |pos: i32, velocity: i32, size: i32| {
+            let (pos, velocity, time, size) = (
+                i64::from(pos),
+                i64::from(velocity),
+                i64::from(time),
+                i64::from(size),
+            );
+            let moved = pos + velocity.rem_euclid(size) * time.rem_euclid(size);
+            moved.rem_euclid(size).try_into().unwrap()
+        };
+
+        ivec2(
+            axis(pos.x, velocity.x, self.0.x),
+            axis(pos.y, velocity.y, self.0.y),
+        )
+    }
 
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().len();
+    /// Whether `pos` is on the middle column or row, which only exist for odd sizes.
+    #[inline]
+    pub fn is_on_center_line(&self, pos: Pos) -> bool {
+        let pos = self.wrap(pos);
+        let is_middle = |pos: i32, size: i32| size.is_odd() && pos == size / 2;
+        is_middle(pos.x, self.0.x) || is_middle(pos.y, self.0.y)
+    }
 
-    debug_assert!(
-        input.lines().all(|line| line.len() == width),
-        "some lines of `input` haven't the same length"
-    );
+    /// Returns the quadrant of `pos`, in reading order from the top-left one,
+    /// or [`None`] if it is on a center line.
+    #[inline]
+    pub fn quadrant(&self, pos: Pos) -> Option<usize> {
+        if self.is_on_center_line(pos) {
+            return None;
+        }
 
+        let pos = self.wrap(pos);
+        let is_second_half = |pos: i32, size: i32| usize::from(pos >= (size + 1) / 2);
+        Some(is_second_half(pos.x, self.0.x) + 2 * is_second_half(pos.y, self.0.y))
+    }
+}
+
+impl From<MapSize> for Torus {
+    fn from(size: MapSize) -> Self {
+        Self::new(size.width(), size.height())
+    }
+}
+
+#[inline]
+pub fn ascii_map_size(input: &str) -> MapSize {
+    let (width, height) = grid::ascii_size(input);
+    MapSize::new(width.try_into().unwrap(), height.try_into().unwrap())
+}
+
+#[inline]
+pub fn ascii_map_size_uvec2(input: &str) -> UVec2 {
+    let (width, height) = grid::ascii_size(input);
     uvec2(width.try_into().unwrap(), height.try_into().unwrap())
 }
 
 // TODO: replace all occurance of this by `parse_ascii_map`
 #[inline]
 pub fn parse_ascii_map_ivec(input: &str) -> impl Iterator<Item = (Pos, u8)> + Clone + '_ {
-    debug_assert!(input.is_ascii());
-    input.lines().enumerate().flat_map(|(y, line)| {
-        let y: i32 = y.try_into().unwrap();
-        line.bytes()
-            .enumerate()
-            .filter(|&(_, b)| (b != b'.'))
-            .map(move |(x, b)| {
-                let x: i32 = x.try_into().unwrap();
-                (pos(x, y), b)
-            })
-    })
+    grid::ascii_cells(input).filter(|&(_, b)| b != b'.')
 }
 
 #[inline]
 pub fn parse_ascii_map(input: &str) -> impl Iterator<Item = (UVec2, u8)> + Clone + '_ {
-    debug_assert!(input.is_ascii());
-    input.lines().enumerate().flat_map(|(y, line)| {
-        let y: u32 = y.try_into().unwrap();
-        line.bytes().enumerate().map(move |(x, b)| {
-            let x: u32 = x.try_into().unwrap();
-            (uvec2(x, y), b)
-        })
-    })
+    grid::ascii_cells(input)
 }
 
 /* -------------------------------------------------------------------------- */
@@ -114,29 +174,201 @@ macro_rules! for_tuple {
 /* -------------------------------------------------------------------------- */
 
 /// Parse a string into a tuple by splitting it by whitespaces.
+///
+/// The elements of the tuple must implement [`ParseItem`], which the integers, floats,
+/// `bool`, `char`, `String`, `IVec2`, `UVec2` and `Vec` of those do.
+/// This is a breaking change from the former [`FromStr`] bound: other [`FromStr`] types
+/// must be wrapped in [`Parsed`], e.g. `parse_tuple::<(Day, u32)>(input)` becomes
+/// `parse_tuple::<(Parsed<Day>, u32)>(input)`, and the value is read back with
+/// `let (Parsed(day), n) = ...`.
 pub fn parse_tuple<T: ParseTuple>(input: &str) -> anyhow::Result<T> {
     <T as ParseTuple>::parse(input)
 }
 
+/// Parse a string into a tuple by splitting it by `delimiter`,
+/// the whitespaces around the items are ignored.
+pub fn parse_tuple_with<T: ParseTuple>(input: &str, delimiter: &str) -> anyhow::Result<T> {
+    <T as ParseTuple>::parse_with(input, delimiter)
+}
+
+/// Parse each line of `input` with [`parse_tuple`],
+/// the errors tell the line number.
+pub fn parse_lines<'a, T: ParseTuple + 'a>(
+    input: &'a str,
+) -> impl Iterator<Item = anyhow::Result<T>> + 'a {
+    with_line_context(input, T::parse)
+}
+
+/// Parse each line of `input` with [`parse_tuple_with`],
+/// the errors tell the line number.
+pub fn parse_lines_with<'a, T: ParseTuple + 'a>(
+    input: &'a str,
+    delimiter: &'a str,
+) -> impl Iterator<Item = anyhow::Result<T>> + 'a {
+    with_line_context(input, move |line| T::parse_with(line, delimiter))
+}
+
+fn with_line_context<'a, T>(
+    input: &'a str,
+    f: impl Fn(&str) -> anyhow::Result<T> + 'a,
+) -> impl Iterator<Item = anyhow::Result<T>> + 'a {
+    use anyhow::Context;
+    input
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| f(line).with_context(|| format!("line {}: `{line}`", i + 1)))
+}
+
+/// A value parsed from a sequence of items, see [`parse_tuple`].
+///
+/// A tuple parses each of its elements with [`ParseItem`], in order, and fails if some items
+/// are left. A `Vec` element consumes all the remaining items, so it may only be the last
+/// element of a tuple: a tuple with a `Vec` before its end always fails to parse.
 pub trait ParseTuple: Sized {
-    fn parse(input: &str) -> anyhow::Result<Self>;
+    /// Parses the items of `input`, separated by whitespaces.
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_items(&mut input.split_whitespace())
+    }
+
+    /// Parses the items of `input`, separated by `delimiter`.
+    fn parse_with(input: &str, delimiter: &str) -> anyhow::Result<Self> {
+        Self::parse_items(&mut input.split(delimiter).map(str::trim))
+    }
+
+    /// Parses all the `items`.
+    fn parse_items(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self>;
+}
+
+/// An element of a [`ParseTuple`].
+///
+/// Other types implementing [`FromStr`] can be parsed with [`Parsed`].
+pub trait ParseItem: Sized {
+    /// Whether the element consumes all the remaining items, it must then be the last one.
+    const GREEDY: bool = false;
+
+    /// Parses the element from the next items.
+    fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self>;
+}
+
+/// An element of a [`ParseTuple`] parsed with its [`FromStr`] implementation,
+/// e.g. `parse_tuple::<(Parsed<Day>, u32)>("1 2")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Parsed<T>(pub T);
+
+impl<T: FromStr> ParseItem for Parsed<T>
+where
+    T::Err: core::fmt::Display,
+{
+    fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        use anyhow::Context;
+        let item = items.next().context("invalid number of items")?;
+        let value = item
+            .parse()
+            .map_err(|e| anyhow::anyhow!("invalid item `{item}`: {e}"))?;
+        Ok(Self(value))
+    }
+}
+
+macro_rules! impl_item_parse {
+    ($($T:ty)*) => {
+        $(
+            impl ParseItem for $T {
+                fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+                    use anyhow::Context;
+                    let item = items.next().context("invalid number of items")?;
+                    item.parse()
+                        .with_context(|| format!("invalid item `{item}`"))
+                }
+            }
+        )*
+    };
+}
+
+impl_item_parse!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 bool char String);
+
+/// Parses all the remaining items, so it must be the last element of a tuple,
+/// parsing a tuple with a `Vec` before its end is an error.
+impl<T: ParseItem> ParseItem for Vec<T> {
+    const GREEDY: bool = true;
+
+    fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        let mut items = items.peekable();
+        let mut values = Vec::new();
+        while items.peek().is_some() {
+            values.push(T::parse_item(&mut items)?);
+        }
+        Ok(values)
+    }
+}
+
+/// Parses an item of the form `x,y`.
+impl ParseItem for IVec2 {
+    fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        use anyhow::Context;
+        let item = items.next().context("invalid number of items")?;
+        Self::parse(item)
+    }
+}
+
+/// Parses an item of the form `x,y`.
+impl ParseItem for UVec2 {
+    fn parse_item(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        use anyhow::Context;
+        let item = items.next().context("invalid number of items")?;
+        Self::parse(item)
+    }
+}
+
+impl<T: ParseItem> ParseTuple for Vec<T> {
+    fn parse_items(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        Self::parse_item(items)
+    }
+}
+
+/// Parses `x,y`.
+impl ParseTuple for IVec2 {
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_with(input, ",")
+    }
+
+    fn parse_items(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        let (x, y) = <(i32, i32)>::parse_items(items)?;
+        Ok(ivec2(x, y))
+    }
+}
+
+/// Parses `x,y`.
+impl ParseTuple for UVec2 {
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_with(input, ",")
+    }
+
+    fn parse_items(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+        let (x, y) = <(u32, u32)>::parse_items(items)?;
+        Ok(uvec2(x, y))
+    }
 }
 
 macro_rules! impl_tuple_parse {
     ($($T:ident)*) => {
         impl<$($T,)*> ParseTuple for ( $($T,)* )
         where
-            $( $T: ::core::str::FromStr, )*
-            $( ::anyhow::Error: From<<$T as ::core::str::FromStr>::Err>, )*
+            $( $T: ParseItem, )*
         {
-            fn parse(input: &str) -> anyhow::Result<Self> {
-                use itertools::Itertools;
-                use anyhow::Context;
-                #[allow(non_snake_case)]
-                let ($($T,)*) = input.split_whitespace().collect_tuple().context("invalid number of items")?;
-                Ok((
-                    $( $T.parse::<$T>()?, )*
-                ))
+            fn parse_items(items: &mut dyn Iterator<Item = &str>) -> anyhow::Result<Self> {
+                let greedy = [$( $T::GREEDY, )*];
+                if let Some((_, init)) = greedy.split_last() {
+                    anyhow::ensure!(
+                        !init.contains(&true),
+                        "only the last element of a tuple can be a `Vec`"
+                    );
+                }
+
+                let tuple = (
+                    $( $T::parse_item(items)?, )*
+                );
+                anyhow::ensure!(items.next().is_none(), "invalid number of items");
+                Ok(tuple)
             }
         }
     };
@@ -175,6 +407,9 @@ impl Dir {
 }
 
 impl Dir {
+    /// All the directions, clockwise from up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
     #[must_use]
     #[inline]
     pub fn rotated_clockwise(self) -> Dir {
@@ -222,6 +457,23 @@ impl Dir {
         }
     }
 
+    /// Returns a unit vector pointing in the direction.
+    ///
+    /// The positive directions are up for Y axis and right for X axis.
+    #[must_use]
+    #[inline]
+    pub fn as_vec_up_right(self) -> glam::IVec2 {
+        Dir8::from(self).as_vec_up_right()
+    }
+
+    /// Moves `pos` one step in the direction, the positive directions are down for Y axis and right for X axis.
+    ///
+    /// Returns [`None`] if the result is negative.
+    #[inline]
+    pub fn checked_step(self, pos: UVec2) -> Option<UVec2> {
+        Dir8::from(self).checked_step(pos)
+    }
+
     #[inline]
     pub fn is_horizontal(self) -> bool {
         matches!(self, Dir::Left | Dir::Right)
@@ -231,6 +483,46 @@ impl Dir {
     pub fn is_vertical(self) -> bool {
         matches!(self, Dir::Up | Dir::Down)
     }
+
+    /// Parses a direction from an arrow (`^>v<`), a letter (`UDLR`) or a cardinal point (`NESW`).
+    #[inline]
+    pub fn from_ascii(c: u8) -> Option<Dir> {
+        match c {
+            b'^' | b'U' | b'N' => Some(Dir::Up),
+            b'>' | b'R' | b'E' => Some(Dir::Right),
+            b'v' | b'D' | b'S' => Some(Dir::Down),
+            b'<' | b'L' | b'W' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+
+    /// Returns the arrow (`^>v<`) of the direction.
+    #[inline]
+    pub fn as_arrow(self) -> char {
+        match self {
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+        }
+    }
+}
+
+impl core::fmt::Display for Dir {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_arrow())
+    }
+}
+
+impl core::str::FromStr for Dir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[c] => Dir::from_ascii(c).ok_or(ParseDirError),
+            _ => Err(ParseDirError),
+        }
+    }
 }
 
 impl From<Horizontal> for Dir {
@@ -253,6 +545,212 @@ impl From<Vertical> for Dir {
 
 /* -------------------------------------------------------------------------- */
 
+/// A direction including the diagonals, see [`Dir`] for the orthogonal ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+#[allow(non_upper_case_globals)]
+impl Dir8 {
+    pub const North: Dir8 = Dir8::Up;
+    pub const NorthEast: Dir8 = Dir8::UpRight;
+    pub const East: Dir8 = Dir8::Right;
+    pub const SouthEast: Dir8 = Dir8::DownRight;
+    pub const South: Dir8 = Dir8::Down;
+    pub const SouthWest: Dir8 = Dir8::DownLeft;
+    pub const West: Dir8 = Dir8::Left;
+    pub const NorthWest: Dir8 = Dir8::UpLeft;
+}
+
+impl Dir8 {
+    /// All the directions, clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    #[inline]
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Rotates the direction by 45°.
+    #[must_use]
+    #[inline]
+    pub fn rotated_clockwise(self) -> Dir8 {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Rotates the direction by 45°.
+    #[must_use]
+    #[inline]
+    pub fn rotated_anti_clockwise(self) -> Dir8 {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Rotates the direction by 90°.
+    #[must_use]
+    #[inline]
+    pub fn rotated_clockwise_90(self) -> Dir8 {
+        Self::ALL[(self.index() + 2) % 8]
+    }
+
+    /// Rotates the direction by 90°.
+    #[must_use]
+    #[inline]
+    pub fn rotated_anti_clockwise_90(self) -> Dir8 {
+        Self::ALL[(self.index() + 6) % 8]
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn opposite(self) -> Dir8 {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    #[inline]
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Returns the orthogonal direction, or [`None`] if it is a diagonal.
+    #[inline]
+    pub fn as_dir(self) -> Option<Dir> {
+        match self {
+            Dir8::Up => Some(Dir::Up),
+            Dir8::Right => Some(Dir::Right),
+            Dir8::Down => Some(Dir::Down),
+            Dir8::Left => Some(Dir::Left),
+            _ => None,
+        }
+    }
+
+    /// Returns a vector pointing in the direction, with components of `-1`, `0` or `1`.
+    ///
+    /// The positive directions are down for Y axis and right for X axis.
+    #[must_use]
+    #[inline]
+    pub fn as_vec_down_right(self) -> glam::IVec2 {
+        let v = self.as_vec_up_right();
+        glam::IVec2::new(v.x, -v.y)
+    }
+
+    /// Returns a vector pointing in the direction, with components of `-1`, `0` or `1`.
+    ///
+    /// The positive directions are up for Y axis and right for X axis.
+    #[must_use]
+    #[inline]
+    pub fn as_vec_up_right(self) -> glam::IVec2 {
+        match self {
+            Dir8::Up => glam::IVec2::new(0, 1),
+            Dir8::UpRight => glam::IVec2::new(1, 1),
+            Dir8::Right => glam::IVec2::new(1, 0),
+            Dir8::DownRight => glam::IVec2::new(1, -1),
+            Dir8::Down => glam::IVec2::new(0, -1),
+            Dir8::DownLeft => glam::IVec2::new(-1, -1),
+            Dir8::Left => glam::IVec2::new(-1, 0),
+            Dir8::UpLeft => glam::IVec2::new(-1, 1),
+        }
+    }
+
+    /// Returns the direction of a vector, or [`None`] if it is zero.
+    ///
+    /// Only the signs of the components are considered, the positive directions
+    /// are down for Y axis and right for X axis.
+    #[inline]
+    pub fn from_vec_down_right(v: glam::IVec2) -> Option<Dir8> {
+        let v = v.signum();
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.as_vec_down_right() == v)
+    }
+
+    /// Moves `pos` one step in the direction, the positive directions are down for Y axis and right for X axis.
+    ///
+    /// Returns [`None`] if the result is negative.
+    #[inline]
+    pub fn checked_step(self, pos: UVec2) -> Option<UVec2> {
+        let v = self.as_vec_down_right();
+        Some(uvec2(
+            pos.x.checked_add_signed(v.x)?,
+            pos.y.checked_add_signed(v.y)?,
+        ))
+    }
+
+    /// Returns the cardinal point of the direction (e.g. `NE`).
+    #[inline]
+    pub fn as_cardinal(self) -> &'static str {
+        match self {
+            Dir8::Up => "N",
+            Dir8::UpRight => "NE",
+            Dir8::Right => "E",
+            Dir8::DownRight => "SE",
+            Dir8::Down => "S",
+            Dir8::DownLeft => "SW",
+            Dir8::Left => "W",
+            Dir8::UpLeft => "NW",
+        }
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(value: Dir) -> Self {
+        match value {
+            Dir::Up => Dir8::Up,
+            Dir::Right => Dir8::Right,
+            Dir::Down => Dir8::Down,
+            Dir::Left => Dir8::Left,
+        }
+    }
+}
+
+impl core::fmt::Display for Dir8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_cardinal())
+    }
+}
+
+impl core::str::FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    /// Parses a cardinal point (e.g. `NE`), or anything [`Dir`] can be parsed from.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.as_cardinal() == s)
+            .or_else(|| s.parse::<Dir>().ok().map(Dir8::from))
+            .ok_or(ParseDirError)
+    }
+}
+
+/// The error returned when parsing a [`Dir`] or a [`Dir8`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDirError;
+
+impl core::fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid direction")
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+/* -------------------------------------------------------------------------- */
+
 #[inline]
 pub fn ascii_array_2d(input: &str) -> Array2D<u8> {
     ascii_array_2d_with(input, |x| x)
@@ -260,16 +758,7 @@ pub fn ascii_array_2d(input: &str) -> Array2D<u8> {
 
 #[inline]
 pub fn ascii_array_2d_with<T>(input: &str, f: impl Fn(u8) -> T) -> Array2D<T> {
-    debug_assert!(input.is_ascii());
-
-    let rows_count = input.lines().count();
-    let columns_count = input.lines().next().unwrap().len();
-    let mut items = input.lines().flat_map(|line| line.bytes()).map(f);
-
-    Array2D::from_elem(
-        array2d::Size::from_rows_columns(rows_count, columns_count),
-        |_, _| items.next().unwrap(),
-    )
+    Grid::parse_ascii(input, f).into()
 }
 
 /* -------------------------------------------------------------------------- */
@@ -403,3 +892,55 @@ impl VecExt for UVec2 {
 }
 
 /* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::template::Day;
+
+    use super::{parse_lines, parse_tuple, parse_tuple_with, Parsed};
+
+    #[test]
+    fn parses_tuples() {
+        assert_eq!(parse_tuple::<(u32, i8)>("3 -4").unwrap(), (3, -4));
+        assert_eq!(
+            parse_tuple_with::<(u32, Vec<u8>)>("3, 1, 2", ",").unwrap(),
+            (3, vec![1, 2])
+        );
+        assert!(parse_tuple::<(u32, u32)>("1 2 3").is_err());
+    }
+
+    #[test]
+    fn parses_from_str_items() {
+        let (Parsed(day), part) = parse_tuple::<(Parsed<Day>, u8)>("7 2").unwrap();
+        assert_eq!(day, crate::day!(7));
+        assert_eq!(part, 2);
+        assert!(parse_tuple::<(Parsed<Day>, u8)>("26 2").is_err());
+    }
+
+    #[test]
+    fn rejects_vec_before_the_end() {
+        let error = parse_tuple::<(Vec<u32>, u32)>("1 2 3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "only the last element of a tuple can be a `Vec`"
+        );
+        assert!(parse_tuple::<(u32, Vec<u32>, u32)>("1 2 3").is_err());
+        assert!(parse_tuple::<(Vec<u32>, Vec<u32>)>("1 2").is_err());
+        assert_eq!(
+            parse_tuple::<(u32, u32, Vec<u32>)>("1 2").unwrap(),
+            (1, 2, vec![])
+        );
+    }
+
+    #[test]
+    fn tells_the_line_of_errors() {
+        let error = parse_lines::<(Vec<u32>, u32)>("1 2\n3 4")
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "line 1: `1 2`: only the last element of a tuple can be a `Vec`"
+        );
+    }
+}
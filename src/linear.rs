@@ -0,0 +1,342 @@
+use core::iter;
+
+use num::rational::Ratio;
+use num::{Integer, One, Signed, Zero};
+
+/// The solutions of a system of linear equations, see [`solve`].
+#[derive(Debug, Clone)]
+pub enum LinearSolution<T> {
+    /// The system has exactly one solution.
+    Unique(Vec<Ratio<T>>),
+    /// The system has an infinity of solutions.
+    Underdetermined(Parameterization<T>),
+    /// The system has no solution.
+    None,
+}
+
+/// The solutions of an underdetermined system:
+/// `particular + Σ t * direction` for any value `t` of each free variable.
+#[derive(Debug, Clone)]
+pub struct Parameterization<T> {
+    /// The solution where all the free variables are zero.
+    pub particular: Vec<Ratio<T>>,
+    /// The index of each free variable with the change of the solution when it increases by one.
+    pub directions: Vec<(usize, Vec<Ratio<T>>)>,
+}
+
+/// Solves `coefficients · x = constants` with Gaussian elimination over fractions.
+///
+/// Each row of `coefficients` is an equation and each column an unknown,
+/// so the system doesn't have to be square.
+///
+/// # Panics
+///
+/// Panics if the rows haven't the same length or if there are not as many constants as rows.
+pub fn solve<T: Integer + Signed + Copy>(
+    coefficients: &[Vec<T>],
+    constants: &[T],
+) -> LinearSolution<T> {
+    assert_eq!(coefficients.len(), constants.len(), "one constant per row");
+
+    let unknowns = coefficients.first().map_or(0, Vec::len);
+
+    // The augmented matrix, reduced to its row echelon form.
+    let mut matrix: Vec<Vec<Ratio<T>>> = coefficients
+        .iter()
+        .zip(constants)
+        .map(|(row, &constant)| {
+            assert_eq!(row.len(), unknowns, "rows haven't the same length");
+            row.iter()
+                .chain([&constant])
+                .map(|&x| Ratio::from_integer(x))
+                .collect()
+        })
+        .collect();
+
+    let mut pivot_columns = Vec::new();
+
+    for column in 0..unknowns {
+        let row = pivot_columns.len();
+        let Some(pivot_row) = (row..matrix.len()).find(|&r| !matrix[r][column].is_zero()) else {
+            continue;
+        };
+        matrix.swap(row, pivot_row);
+
+        let pivot = matrix[row][column];
+        matrix[row].iter_mut().for_each(|x| *x = *x / pivot);
+
+        let pivot_values = matrix[row].clone();
+        for (other, values) in matrix.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor.is_zero() {
+                continue;
+            }
+            for (x, &p) in values.iter_mut().zip(&pivot_values).skip(column) {
+                *x = *x - p * factor;
+            }
+        }
+
+        pivot_columns.push(column);
+    }
+
+    // Remaining rows are `0 = constant`.
+    if matrix[pivot_columns.len()..]
+        .iter()
+        .any(|row| !row[unknowns].is_zero())
+    {
+        return LinearSolution::None;
+    }
+
+    let mut particular = vec![Ratio::zero(); unknowns];
+    for (row, &column) in pivot_columns.iter().enumerate() {
+        particular[column] = matrix[row][unknowns];
+    }
+
+    if pivot_columns.len() == unknowns {
+        return LinearSolution::Unique(particular);
+    }
+
+    let directions = (0..unknowns)
+        .filter(|column| !pivot_columns.contains(column))
+        .map(|free| {
+            let mut direction = vec![Ratio::zero(); unknowns];
+            direction[free] = Ratio::one();
+            for (row, &column) in pivot_columns.iter().enumerate() {
+                direction[column] = -matrix[row][free];
+            }
+            (free, direction)
+        })
+        .collect();
+
+    LinearSolution::Underdetermined(Parameterization {
+        particular,
+        directions,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl<T: Integer + Signed + Copy> LinearSolution<T> {
+    /// Finds the solution with non-negative integer values that minimises `Σ cost * x`.
+    ///
+    /// Returns the solution and its cost, or [`None`] if there is no such solution
+    /// or if the cost has no minimum.
+    ///
+    /// With several free variables, their values are enumerated within the bounds given
+    /// by the equations, [`None`] is also returned if one of them has no upper bound.
+    pub fn min_cost_non_negative(&self, cost: &[T]) -> Option<(Vec<T>, T)> {
+        match self {
+            LinearSolution::None => None,
+            LinearSolution::Unique(x) => {
+                let x = to_non_negative_integers(x)?;
+                let cost = total_cost(&x, cost);
+                Some((x, cost))
+            }
+            LinearSolution::Underdetermined(parameterization) => {
+                let x = parameterization.min_cost_non_negative(cost)?;
+                let cost = total_cost(&x, cost);
+                Some((x, cost))
+            }
+        }
+    }
+}
+
+impl<T: Integer + Signed + Copy> Parameterization<T> {
+    /// Returns the solution for the given values of the free variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one value per free variable.
+    pub fn at(&self, free_values: &[T]) -> Vec<Ratio<T>> {
+        assert_eq!(free_values.len(), self.directions.len());
+
+        let mut x = self.particular.clone();
+        for ((_, direction), &t) in self.directions.iter().zip(free_values) {
+            for (x, &d) in x.iter_mut().zip(direction) {
+                *x = *x + d * t;
+            }
+        }
+        x
+    }
+
+    fn min_cost_non_negative(&self, cost: &[T]) -> Option<Vec<T>> {
+        match self.directions.as_slice() {
+            [] => to_non_negative_integers(&self.particular),
+            [(_, direction)] => self.min_cost_non_negative_1d(direction, cost),
+            [(_, first), rest @ ..] => {
+                // Fix the first free variable to each possible value, and solve for the others.
+                let max = self.free_variable_max(first, rest)?;
+                if max.is_negative() {
+                    return None;
+                }
+
+                iter::successors(Some(T::zero()), |&t| (t < max).then(|| t + T::one()))
+                    .filter_map(|t| {
+                        let reduced = Parameterization {
+                            particular: self
+                                .particular
+                                .iter()
+                                .zip(first)
+                                .map(|(&p, &d)| p + d * t)
+                                .collect(),
+                            directions: rest.to_vec(),
+                        };
+                        reduced.min_cost_non_negative(cost)
+                    })
+                    .min_by_key(|x| total_cost(x, cost))
+            }
+        }
+    }
+
+    /// Returns the largest value of the free variable of `direction` for which the solution
+    /// can be non-negative, given that the free variables of `others` are non-negative,
+    /// or [`None`] if there is no such bound.
+    fn free_variable_max(
+        &self,
+        direction: &[Ratio<T>],
+        others: &[(usize, Vec<Ratio<T>>)],
+    ) -> Option<T> {
+        // `p + t * d + Σ u * e >= 0` with all the `e <= 0` gives `t <= -p / d` when `d < 0`.
+        (0..self.particular.len())
+            .filter(|&i| {
+                direction[i].is_negative()
+                    && others.iter().all(|(_, other)| !other[i].is_positive())
+            })
+            .map(|i| (-self.particular[i] / direction[i]).floor().to_integer())
+            .min()
+    }
+
+    fn min_cost_non_negative_1d(&self, direction: &[Ratio<T>], cost: &[T]) -> Option<Vec<T>> {
+        // The range of `t` for which the solution is non-negative.
+        let mut min = None::<T>;
+        let mut max = None::<T>;
+        for (&p, &d) in self.particular.iter().zip(direction) {
+            if d.is_zero() {
+                if p.is_negative() {
+                    return None;
+                }
+                continue;
+            }
+
+            // `p + t * d >= 0`
+            let bound = -p / d;
+            if d.is_positive() {
+                let bound = bound.ceil().to_integer();
+                min = Some(min.map_or(bound, |min| min.max(bound)));
+            } else {
+                let bound = bound.floor().to_integer();
+                max = Some(max.map_or(bound, |max| max.min(bound)));
+            }
+        }
+
+        let slope = direction
+            .iter()
+            .zip(cost)
+            .fold(Ratio::zero(), |acc: Ratio<T>, (&d, &c)| acc + d * c);
+
+        // The solutions are integers periodically, search from the best end of the range.
+        let period = direction.iter().fold(T::one(), |acc, d| acc.lcm(d.denom()));
+        let (mut t, step, end) = if slope.is_negative() {
+            (max?, -T::one(), min)
+        } else {
+            (min?, T::one(), max)
+        };
+
+        let mut tries = T::zero();
+        while tries < period && end.is_none_or(|end| (end - t) * step >= T::zero()) {
+            if let Some(x) = to_non_negative_integers(&self.at(&[t])) {
+                return Some(x);
+            }
+            t = t + step;
+            tries = tries + T::one();
+        }
+
+        None
+    }
+}
+
+fn total_cost<T: Integer + Copy>(x: &[T], cost: &[T]) -> T {
+    x.iter()
+        .zip(cost)
+        .fold(T::zero(), |acc, (&x, &c)| acc + x * c)
+}
+
+fn to_non_negative_integers<T: Integer + Signed + Copy>(x: &[Ratio<T>]) -> Option<Vec<T>> {
+    x.iter()
+        .map(|x| (x.is_integer() && !x.is_negative()).then(|| x.to_integer()))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+
+    use super::{solve, LinearSolution};
+
+    fn ratios(values: &[i64]) -> Vec<Ratio<i64>> {
+        values.iter().map(|&x| Ratio::from_integer(x)).collect()
+    }
+
+    #[test]
+    fn solves_unique_systems() {
+        let solution = solve(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+        let LinearSolution::Unique(x) = &solution else {
+            panic!("expected a unique solution, got {solution:?}");
+        };
+        assert_eq!(x, &ratios(&[80, 40]));
+        assert_eq!(
+            solution.min_cost_non_negative(&[3, 1]),
+            Some((vec![80, 40], 280))
+        );
+
+        let solution = solve(&[vec![2, 0], vec![0, 4]], &[1, 2]);
+        assert_eq!(solution.min_cost_non_negative(&[1, 1]), None);
+    }
+
+    #[test]
+    fn solves_underdetermined_systems() {
+        // `x + 4y = 10` twice.
+        let solution = solve(&[vec![1, 4], vec![2, 8]], &[10, 20]);
+        let LinearSolution::Underdetermined(parameterization) = &solution else {
+            panic!("expected an infinity of solutions, got {solution:?}");
+        };
+        assert_eq!(parameterization.particular, ratios(&[10, 0]));
+        assert_eq!(parameterization.directions, [(1, ratios(&[-4, 1]))]);
+        assert_eq!(parameterization.at(&[2]), ratios(&[2, 2]));
+
+        assert_eq!(
+            solution.min_cost_non_negative(&[1, 3]),
+            Some((vec![2, 2], 8))
+        );
+        assert_eq!(
+            solution.min_cost_non_negative(&[1, 5]),
+            Some((vec![10, 0], 10))
+        );
+    }
+
+    #[test]
+    fn solves_systems_with_several_free_variables() {
+        let solution = solve(&[vec![2, 3, 5]], &[17]);
+        let LinearSolution::Underdetermined(parameterization) = &solution else {
+            panic!("expected an infinity of solutions, got {solution:?}");
+        };
+        assert_eq!(parameterization.directions.len(), 2);
+        assert_eq!(
+            solution.min_cost_non_negative(&[1, 1, 1]),
+            Some((vec![1, 0, 3], 4))
+        );
+
+        // `y` can grow without bound.
+        let solution = solve(&[vec![1, -1, 1]], &[3]);
+        assert_eq!(solution.min_cost_non_negative(&[1, 1, 1]), None);
+    }
+
+    #[test]
+    fn detects_systems_without_solution() {
+        let solution = solve(&[vec![1, 1], vec![2, 2]], &[1, 3]);
+        assert!(matches!(solution, LinearSolution::None));
+        assert_eq!(solution.min_cost_non_negative(&[1, 1]), None);
+    }
+}
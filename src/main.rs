@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, read, scaffold, solve, stars, stats, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,7 +9,7 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, DaySet};
     use std::process;
 
     pub enum AppArguments {
@@ -29,13 +31,16 @@ mod args {
             submit: Option<u8>,
         },
         All {
+            days: Option<DaySet>,
             release: bool,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
         },
+        Stats,
+        Stars,
         #[cfg(feature = "today")]
         Today,
     }
@@ -44,19 +49,26 @@ mod args {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                let release = args.contains("--release");
+
+                AppArguments::All {
+                    days: args.opt_free_from_str()?,
+                    release,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: args.opt_free_from_str()?,
                     store,
                 }
             }
+            Some("stats") => AppArguments::Stats,
+            Some("stars") => AppArguments::Stars,
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
@@ -102,8 +114,10 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All { days, release } => all::handle(days, release),
+            AppArguments::Time { days, all, store } => time::handle(days, all, store),
+            AppArguments::Stats => stats::handle(),
+            AppArguments::Stars => stars::handle(),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
@@ -0,0 +1,248 @@
+use num::{CheckedAdd, CheckedMul, Integer, Signed};
+
+/// Computes the gcd of `a` and `b` with the Bézout coefficients.
+///
+/// Returns `(g, x, y)` such that `a * x + b * y = g`.
+#[inline]
+pub fn extended_gcd<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let q = old_r.div_floor(&r);
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Computes `x` in `0..m` such that `a * x ≡ 1 (mod m)`,
+/// or [`None`] if `a` and `m` aren't coprime.
+#[inline]
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a, m);
+    g.is_one().then(|| x.mod_floor(&m))
+}
+
+/// Solves a system of congruences `x ≡ r (mod m)` given as `(r, m)`,
+/// the moduli don't have to be coprime.
+///
+/// Returns `(x, lcm)` where `x` is in `0..lcm`, the lcm of the moduli,
+/// or [`None`] if the system has no solution, a modulus isn't positive
+/// or the lcm doesn't fit in `T`.
+pub fn crt<T: Integer + Signed + CheckedAdd + CheckedMul + Copy>(
+    congruences: impl IntoIterator<Item = (T, T)>,
+) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::zero(), T::one()), |(x, m), (r, n)| {
+            if !n.is_positive() {
+                return None;
+            }
+
+            let (g, p, _) = extended_gcd(m, n);
+            let (q, rem) = (r - x).div_rem(&g);
+            if !rem.is_zero() {
+                return None;
+            }
+
+            let n_g = n / g;
+            let lcm = (m / g).checked_mul(&n)?;
+            let k = q
+                .mod_floor(&n_g)
+                .checked_mul(&p.mod_floor(&n_g))?
+                .mod_floor(&n_g);
+            let x = x.checked_add(&m.checked_mul(&k)?)?.mod_floor(&lcm);
+            Some((x, lcm))
+        })
+}
+
+/// Computes the gcd of all the values, `0` if there is none.
+#[inline]
+pub fn gcd<T: Integer + Copy>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::zero(), |acc, x| acc.gcd(&x))
+}
+
+/// Computes the lcm of all the values, `1` if there is none.
+#[inline]
+pub fn lcm<T: Integer + Copy>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), |acc, x| acc.lcm(&x))
+}
+
+/// Computes `base ^ exp mod modulus`.
+///
+/// Note: `(modulus - 1)²` must fit in `T`.
+#[inline]
+pub fn pow_mod<T: Integer + Copy>(base: T, exp: T, modulus: T) -> T {
+    let two = T::one() + T::one();
+
+    let mut result = T::one().mod_floor(&modulus);
+    let mut base = base.mod_floor(&modulus);
+    let mut exp = exp;
+
+    while exp > T::zero() {
+        if exp.is_odd() {
+            result = (result * base).mod_floor(&modulus);
+        }
+        base = (base * base).mod_floor(&modulus);
+        exp = exp / two;
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes `10 ^ exp`.
+///
+/// # Panics
+///
+/// Panics if the result doesn't fit in `T`.
+#[inline]
+pub fn pow10<T: Integer + CheckedMul + Copy + From<u8>>(exp: u32) -> T {
+    num::checked_pow(T::from(10), exp.try_into().unwrap()).expect("power of 10 overflow")
+}
+
+/// Counts the digits of `n` in base 10, `0` has one digit.
+#[inline]
+pub fn digit_count<T: Integer + Copy + From<u8>>(n: T) -> u32 {
+    let ten = T::from(10);
+    let mut n = n / ten;
+    let mut count = 1;
+    while !n.is_zero() {
+        n = n / ten;
+        count += 1;
+    }
+    count
+}
+
+/// Splits the last `count` digits of `n` from the others,
+/// e.g. `split_digits(1234, 1)` returns `(123, 4)`.
+#[inline]
+pub fn split_digits<T: Integer + CheckedMul + Copy + From<u8>>(n: T, count: u32) -> (T, T) {
+    n.div_rem(&pow10(count))
+}
+
+/// Appends the digits of `rhs` to the ones of `lhs`,
+/// e.g. `concat_digits(12, 345)` returns `12345`.
+#[inline]
+pub fn concat_digits<T: Integer + CheckedMul + Copy + From<u8>>(lhs: T, rhs: T) -> T {
+    lhs * pow10(digit_count(rhs)) + rhs
+}
+
+/// Reverses the digits of `n`, e.g. `reverse_digits(1230)` returns `321`.
+#[inline]
+pub fn reverse_digits<T: Integer + Copy + From<u8>>(n: T) -> T {
+    let ten = T::from(10);
+    let mut n = n;
+    let mut reversed = T::zero();
+    while !n.is_zero() {
+        let (q, digit) = n.div_rem(&ten);
+        reversed = reversed * ten + digit;
+        n = q;
+    }
+    reversed
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use num::Integer;
+
+    use super::{
+        concat_digits, crt, digit_count, extended_gcd, mod_inverse, pow10, pow_mod, reverse_digits,
+        split_digits,
+    };
+
+    #[test]
+    fn finds_the_bezout_coefficients() {
+        for (a, b) in [
+            (240, 46),
+            (-240, 46),
+            (240, -46),
+            (-240, -46),
+            (0, 7),
+            (7, 0),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(g, a.gcd(&b), "gcd of {a} and {b}");
+            assert_eq!(a * x + b * y, g, "Bézout identity for {a} and {b}");
+        }
+    }
+
+    #[test]
+    fn computes_modular_inverses() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 5), None);
+    }
+
+    #[test]
+    fn solves_coprime_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(0_i64, 101), (0, 103)]), Some((0, 10_403)));
+        assert_eq!(crt::<i32>([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn solves_non_coprime_congruences() {
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(3, 4), (3, 6), (0, 9)]), Some((27, 36)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn rejects_non_positive_moduli() {
+        assert_eq!(crt([(1, 3), (0, 0)]), None);
+        assert_eq!(crt([(1, -3)]), None);
+    }
+
+    #[test]
+    fn detects_lcm_overflow() {
+        let big = i64::MAX / 2;
+        assert_eq!(crt([(1, big), (2, big - 2)]), None);
+        assert!(crt([(1_i128, i128::from(big)), (2, i128::from(big) - 2)]).is_some());
+    }
+
+    #[test]
+    fn computes_modular_powers() {
+        assert_eq!(pow_mod(4_u64, 13, 497), 445);
+        assert_eq!(pow_mod(4_u64, 0, 497), 1);
+        assert_eq!(pow_mod(4_u64, 13, 1), 0);
+        assert_eq!(pow_mod(4_u64, 0, 1), 0);
+    }
+
+    #[test]
+    fn computes_powers_of_10() {
+        assert_eq!(pow10::<u64>(0), 1);
+        assert_eq!(pow10::<u64>(19), 10_000_000_000_000_000_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "power of 10 overflow")]
+    fn panics_on_pow10_overflow() {
+        pow10::<u64>(20);
+    }
+
+    #[test]
+    fn handles_zero_digits() {
+        assert_eq!(digit_count(0_u32), 1);
+        assert_eq!(digit_count(10_u32), 2);
+        assert_eq!(split_digits(1230_u32, 1), (123, 0));
+        assert_eq!(split_digits(7_u32, 2), (0, 7));
+        assert_eq!(concat_digits(12_u32, 0), 120);
+        assert_eq!(concat_digits(0_u32, 12), 12);
+        assert_eq!(reverse_digits(1230_u32), 321);
+        assert_eq!(reverse_digits(0_u32), 0);
+    }
+}
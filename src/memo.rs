@@ -0,0 +1,138 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A cache of the results of a function, usually a recursive one.
+///
+/// Keys can be borrowed (e.g. `&[u8]`) or tuples of the arguments.
+///
+/// ```
+/// use advent_of_code::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     if n < 2 {
+///         return n;
+///     }
+///     memo.get_or_insert_with(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+/// }
+///
+/// assert_eq!(fibonacci(&mut Memo::new(), 90), 2_880_067_194_370_816_120);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            limit: None,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a cache that only keeps the first `limit` results.
+    ///
+    /// Nothing is ever evicted: once it is full, the new results are computed on every call
+    /// and aren't cached, so it only helps when the first results are the most reused ones
+    /// (e.g. the small arguments of a recursion).
+    #[inline]
+    pub fn caching_first(limit: usize) -> Self {
+        Self {
+            cache: HashMap::new(),
+            limit: Some(limit),
+        }
+    }
+
+    /// Returns the cached result for `key`, or computes it with `f` and caches it.
+    ///
+    /// `f` is given the cache so that it can be used for the recursive calls.
+    #[inline]
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.cache.get(key)
+    }
+
+    /// Caches `value` for `key`, unless the cache is full, see [`Memo::caching_first`].
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+            self.cache.insert(key, value);
+        }
+    }
+
+    /// The number of cached results.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::Memo;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn caches_results() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(memo.get(&10), Some(&55));
+    }
+
+    #[test]
+    fn only_caches_the_first_results() {
+        let mut memo = Memo::caching_first(2);
+        memo.insert(1, "a");
+        memo.insert(2, "b");
+        memo.insert(3, "c");
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&3), None);
+
+        assert_eq!(memo.get_or_insert_with(4, |_| "d"), "d");
+        assert_eq!(memo.get(&4), None);
+        assert_eq!(memo.get_or_insert_with(1, |_| "z"), "a");
+    }
+}
@@ -1,7 +1,10 @@
 use core::ops::RangeInclusive;
 
-use glam::{ivec2, uvec2, UVec2};
+use glam::{ivec2, IVec2, UVec2};
 
+use super::Shape;
+
+/// The points at a Manhattan distance of at most `radius`, see [`super::circle`].
 #[derive(Debug, Clone)]
 pub struct Circle {
     dx: i32,
@@ -23,13 +26,12 @@ impl Circle {
         Self { dx, x, y }
     }
 
+    /// Moves the origin of the circle to `center`, skipping the points with a negative coordinate.
+    ///
+    /// See [`Shape::with_center`], this one doesn't need the trait to be in scope.
     #[inline]
     pub fn with_center(self, center: UVec2) -> impl Iterator<Item = UVec2> + Clone {
-        self.filter_map(move |p| {
-            let x = center.x.checked_add_signed(p.x)?;
-            let y = center.y.checked_add_signed(p.y)?;
-            Some(uvec2(x, y))
-        })
+        Shape::with_center(self, center)
     }
 }
 
@@ -54,3 +56,99 @@ impl Iterator for Circle {
         }
     }
 }
+
+impl Shape for Circle {
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        offset.abs().element_sum().try_into().unwrap()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The points at a Manhattan distance of exactly `radius`, see [`super::manhattan_ring`].
+#[derive(Debug, Clone)]
+pub struct ManhattanRing {
+    radius: i32,
+    x: RangeInclusive<i32>,
+    /// The point below the axis, yielded after the one above it.
+    pending: Option<IVec2>,
+}
+
+impl ManhattanRing {
+    #[inline]
+    pub fn new(radius: i32) -> Self {
+        Self {
+            radius,
+            x: -radius..=radius,
+            pending: None,
+        }
+    }
+}
+
+impl Iterator for ManhattanRing {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.pending.take() {
+            return Some(p);
+        }
+
+        let x = self.x.next()?;
+        let dy = self.radius - x.abs();
+        if dy != 0 {
+            self.pending = Some(ivec2(x, dy));
+        }
+
+        Some(ivec2(x, -dy))
+    }
+}
+
+impl Shape for ManhattanRing {
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        Circle::distance(offset)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use glam::ivec2;
+
+    use super::{Circle, ManhattanRing, Shape};
+
+    #[test]
+    fn yields_the_points_of_the_circle() {
+        assert_eq!(Circle::new(0).collect::<Vec<_>>(), [ivec2(0, 0)]);
+        assert_eq!(Circle::new(1).count(), 5);
+        assert_eq!(Circle::new(2).count(), 13);
+    }
+
+    #[test]
+    fn yields_4r_points_on_the_ring() {
+        for radius in 1..=5 {
+            let points = ManhattanRing::new(radius).collect::<HashSet<_>>();
+
+            assert_eq!(points.len(), (4 * radius).try_into().unwrap());
+            assert!(points
+                .iter()
+                .all(|&p| ManhattanRing::distance(p) == radius.try_into().unwrap()));
+        }
+    }
+
+    #[test]
+    fn yields_the_origin_for_a_zero_radius_ring() {
+        assert_eq!(ManhattanRing::new(0).collect::<Vec<_>>(), [ivec2(0, 0)]);
+    }
+
+    #[test]
+    fn is_empty_for_negative_radius() {
+        assert_eq!(ManhattanRing::new(-1).count(), 0);
+        assert_eq!(ManhattanRing::new(-3).count(), 0);
+    }
+}
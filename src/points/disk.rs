@@ -0,0 +1,89 @@
+use core::ops::RangeInclusive;
+
+use glam::{ivec2, IVec2};
+
+use super::Shape;
+
+/// The points at a Euclidean distance of at most `radius`, see [`super::disk`].
+#[derive(Debug, Clone)]
+pub struct Disk {
+    radius: i32,
+    dx: i32,
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+impl Disk {
+    /// The disk is empty if `radius` is negative.
+    #[inline]
+    pub fn new(radius: i32) -> Self {
+        let mut x = -radius..=radius;
+        let (dx, y) = match x.next() {
+            Some(dx) => (dx, Self::column(radius, dx)),
+            #[allow(clippy::reversed_empty_ranges)]
+            None => (0, 1..=0),
+        };
+
+        Self { radius, dx, x, y }
+    }
+
+    /// The squared Euclidean distance of `offset` to the origin, to stay in integers.
+    #[inline]
+    pub fn distance_squared(offset: IVec2) -> u32 {
+        offset.length_squared().try_into().unwrap()
+    }
+
+    /// The range of `y` of the points of the column `x`.
+    #[inline]
+    fn column(radius: i32, x: i32) -> RangeInclusive<i32> {
+        let y_range = (radius * radius - x * x).max(0).isqrt();
+        -y_range..=y_range
+    }
+}
+
+impl Iterator for Disk {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.y.next() {
+            Some(y) => Some(ivec2(self.dx, y)),
+            None => {
+                self.dx = self.x.next()?;
+                self.y = Self::column(self.radius, self.dx);
+                self.y.next().map(|y| ivec2(self.dx, y))
+            }
+        }
+    }
+}
+
+impl Shape for Disk {
+    /// The squared Euclidean distance, see [`Disk::distance_squared`].
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        Self::distance_squared(offset)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use glam::ivec2;
+
+    use super::Disk;
+
+    #[test]
+    fn yields_the_points_of_the_disk() {
+        assert_eq!(Disk::new(0).collect::<Vec<_>>(), [ivec2(0, 0)]);
+        assert_eq!(Disk::new(1).count(), 5);
+        assert_eq!(Disk::new(2).count(), 13);
+        assert!(Disk::new(3).all(|p| Disk::distance_squared(p) <= 9));
+    }
+
+    #[test]
+    fn is_empty_for_negative_radius() {
+        assert_eq!(Disk::new(-1).count(), 0);
+        assert_eq!(Disk::new(-3).count(), 0);
+    }
+}
@@ -0,0 +1,125 @@
+use glam::IVec2;
+
+use super::Shape;
+
+/// The points of a line from the origin, see [`super::line`].
+///
+/// Uses the Bresenham's algorithm.
+#[derive(Debug, Clone)]
+pub struct Line {
+    current: Option<IVec2>,
+    to: IVec2,
+    delta: IVec2,
+    step: IVec2,
+    error: i32,
+}
+
+impl Line {
+    #[inline]
+    pub fn new(to: IVec2) -> Self {
+        let delta = IVec2::new(to.x.abs(), -to.y.abs());
+
+        Self {
+            current: Some(IVec2::ZERO),
+            to,
+            delta,
+            step: to.signum(),
+            error: delta.x + delta.y,
+        }
+    }
+}
+
+impl Iterator for Line {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+
+        if current == self.to {
+            self.current = None;
+            return Some(current);
+        }
+
+        let mut next = current;
+        let error = 2 * self.error;
+        if error >= self.delta.y {
+            self.error += self.delta.y;
+            next.x += self.step.x;
+        }
+        if error <= self.delta.x {
+            self.error += self.delta.x;
+            next.y += self.step.y;
+        }
+        self.current = Some(next);
+
+        Some(current)
+    }
+}
+
+impl Shape for Line {
+    /// The number of steps from the origin.
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        offset.abs().max_element().try_into().unwrap()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use glam::{ivec2, IVec2};
+
+    use super::Line;
+
+    #[test]
+    fn yields_the_origin_for_a_zero_length_line() {
+        assert_eq!(Line::new(IVec2::ZERO).collect::<Vec<_>>(), [IVec2::ZERO]);
+    }
+
+    #[test]
+    fn yields_straight_lines() {
+        assert_eq!(
+            Line::new(ivec2(3, 0)).collect::<Vec<_>>(),
+            [ivec2(0, 0), ivec2(1, 0), ivec2(2, 0), ivec2(3, 0)]
+        );
+        assert_eq!(
+            Line::new(ivec2(0, -2)).collect::<Vec<_>>(),
+            [ivec2(0, 0), ivec2(0, -1), ivec2(0, -2)]
+        );
+        assert_eq!(
+            Line::new(ivec2(-2, 2)).collect::<Vec<_>>(),
+            [ivec2(0, 0), ivec2(-1, 1), ivec2(-2, 2)]
+        );
+    }
+
+    #[test]
+    fn connects_the_ends_in_all_octants() {
+        // A shallow and a steep slope in each quadrant, so that every octant is covered.
+        for (a, b) in [(5, 2), (2, 5)] {
+            for sign in [ivec2(1, 1), ivec2(-1, 1), ivec2(-1, -1), ivec2(1, -1)] {
+                let to = ivec2(a, b) * sign;
+                let points = Line::new(to).collect::<Vec<_>>();
+
+                assert_eq!(points.first(), Some(&IVec2::ZERO), "line to {to}");
+                assert_eq!(points.last(), Some(&to), "line to {to}");
+                assert_eq!(points.len(), 6, "line to {to}");
+                assert!(
+                    points
+                        .windows(2)
+                        .all(|w| (w[1] - w[0]).abs().max_element() == 1),
+                    "line to {to} has a gap: {points:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_symmetric_around_the_origin() {
+        let forward = Line::new(ivec2(4, 1)).collect::<Vec<_>>();
+        let backward = Line::new(ivec2(-4, -1)).map(|p| -p).collect::<Vec<_>>();
+
+        assert_eq!(forward, backward);
+    }
+}
@@ -1,7 +1,141 @@
 mod circle;
+mod disk;
+mod line;
+mod ray;
+mod square;
 
-pub use circle::Circle;
+pub use circle::{Circle, ManhattanRing};
+pub use disk::Disk;
+pub use line::Line;
+pub use ray::Ray;
+pub use square::{Square, SquareRing};
 
+use glam::{uvec2, IVec2, UVec2};
+
+use crate::{Dir8, MapSize};
+
+/// An iterator over the points of a shape, relative to its origin.
+pub trait Shape: Iterator<Item = IVec2> + Clone {
+    /// The distance of a point of the shape to its origin, in the metric of the shape.
+    fn distance(offset: IVec2) -> u32;
+
+    /// Yields the points along with their distance to the origin.
+    #[inline]
+    fn with_distance(self) -> impl Iterator<Item = (IVec2, u32)> + Clone {
+        self.map(|p| (p, Self::distance(p)))
+    }
+
+    /// Moves the origin of the shape to `center`, skipping the points with a negative coordinate.
+    #[inline]
+    fn with_center(self, center: UVec2) -> impl Iterator<Item = UVec2> + Clone {
+        self.filter_map(move |p| {
+            let x = center.x.checked_add_signed(p.x)?;
+            let y = center.y.checked_add_signed(p.y)?;
+            Some(uvec2(x, y))
+        })
+    }
+
+    /// Moves the origin of the shape to `center`, skipping the points out of `size`.
+    ///
+    /// Yields the points along with their distance to `center`.
+    #[inline]
+    fn centered_in(
+        self,
+        center: IVec2,
+        size: MapSize,
+    ) -> impl Iterator<Item = (IVec2, u32)> + Clone {
+        self.filter_map(move |p| {
+            let pos = center + p;
+            size.contains(pos).then(|| (pos, Self::distance(p)))
+        })
+    }
+}
+
+/// Returns the points at a Manhattan distance of at most `radius`.
+#[inline]
 pub fn circle(radius: i32) -> Circle {
     Circle::new(radius)
 }
+
+/// Returns the points at a Manhattan distance of exactly `radius`.
+#[inline]
+pub fn manhattan_ring(radius: i32) -> ManhattanRing {
+    ManhattanRing::new(radius)
+}
+
+/// Returns the points at a Chebyshev distance of at most `radius`.
+#[inline]
+pub fn square(radius: i32) -> Square {
+    Square::new(radius)
+}
+
+/// Returns the points at a Chebyshev distance of exactly `radius`.
+#[inline]
+pub fn square_ring(radius: i32) -> SquareRing {
+    SquareRing::new(radius)
+}
+
+/// Returns the points at a Euclidean distance of at most `radius`.
+#[inline]
+pub fn disk(radius: i32) -> Disk {
+    Disk::new(radius)
+}
+
+/// Returns the points of the line from the origin to `to`, both included.
+#[inline]
+pub fn line(to: IVec2) -> Line {
+    Line::new(to)
+}
+
+/// Returns the `length` points in the direction `dir`, the origin excluded.
+#[inline]
+pub fn ray(dir: impl Into<Dir8>, length: i32) -> Ray {
+    Ray::new(dir, length)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use glam::{ivec2, uvec2};
+
+    use super::{square, Shape};
+    use crate::MapSize;
+
+    #[test]
+    fn clips_to_the_map_when_centered_in() {
+        // The bottom right corner, only the points up and left of it stay in the map.
+        let mut points = square(1)
+            .centered_in(ivec2(2, 2), MapSize::new(3, 3))
+            .collect::<Vec<_>>();
+        points.sort_unstable_by_key(|&(p, _)| (p.y, p.x));
+
+        assert_eq!(
+            points,
+            [
+                (ivec2(1, 1), 1),
+                (ivec2(2, 1), 1),
+                (ivec2(1, 2), 1),
+                (ivec2(2, 2), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_negative_coordinates_with_center() {
+        let mut points = square(1).with_center(uvec2(0, 1)).collect::<Vec<_>>();
+        points.sort_unstable_by_key(|p| (p.y, p.x));
+
+        assert_eq!(
+            points,
+            [
+                uvec2(0, 0),
+                uvec2(1, 0),
+                uvec2(0, 1),
+                uvec2(1, 1),
+                uvec2(0, 2),
+                uvec2(1, 2)
+            ]
+        );
+    }
+}
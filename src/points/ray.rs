@@ -0,0 +1,104 @@
+use core::ops::RangeInclusive;
+
+use glam::IVec2;
+
+use super::Shape;
+use crate::{Dir8, MapSize};
+
+/// The points in a direction from the origin, see [`super::ray`].
+///
+/// The positive directions are down for Y axis and right for X axis.
+#[derive(Debug, Clone)]
+pub struct Ray {
+    dir: IVec2,
+    steps: RangeInclusive<i32>,
+}
+
+impl Ray {
+    #[inline]
+    pub fn new(dir: impl Into<Dir8>, length: i32) -> Self {
+        Self {
+            dir: dir.into().as_vec_down_right(),
+            steps: 1..=length,
+        }
+    }
+
+    /// Creates a ray that only ends with [`Shape::centered_in`].
+    #[inline]
+    pub fn unbounded(dir: impl Into<Dir8>) -> Self {
+        Self::new(dir, i32::MAX)
+    }
+}
+
+impl Iterator for Ray {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.steps.next().map(|step| self.dir * step)
+    }
+}
+
+impl Shape for Ray {
+    /// The number of steps from the origin.
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        offset.abs().max_element().try_into().unwrap()
+    }
+
+    /// Stops at the first point out of `size`.
+    #[inline]
+    fn centered_in(
+        self,
+        center: IVec2,
+        size: MapSize,
+    ) -> impl Iterator<Item = (IVec2, u32)> + Clone {
+        self.map_while(move |p| {
+            let pos = center + p;
+            size.contains(pos).then(|| (pos, Self::distance(p)))
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use glam::ivec2;
+
+    use super::{Ray, Shape};
+    use crate::{Dir8, MapSize};
+
+    #[test]
+    fn yields_the_points_in_the_direction() {
+        assert_eq!(
+            Ray::new(Dir8::DownLeft, 3).collect::<Vec<_>>(),
+            [ivec2(-1, 1), ivec2(-2, 2), ivec2(-3, 3)]
+        );
+        assert_eq!(Ray::new(Dir8::Up, 0).count(), 0);
+    }
+
+    #[test]
+    fn stops_at_the_first_point_out_of_the_map() {
+        let size = MapSize::new(4, 3);
+
+        assert_eq!(
+            Ray::unbounded(Dir8::Right)
+                .centered_in(ivec2(1, 1), size)
+                .collect::<Vec<_>>(),
+            [(ivec2(2, 1), 1), (ivec2(3, 1), 2)]
+        );
+        assert_eq!(
+            Ray::unbounded(Dir8::UpLeft)
+                .centered_in(ivec2(2, 2), size)
+                .collect::<Vec<_>>(),
+            [(ivec2(1, 1), 1), (ivec2(0, 0), 2)]
+        );
+        assert_eq!(
+            Ray::unbounded(Dir8::Down)
+                .centered_in(ivec2(0, 2), size)
+                .count(),
+            0
+        );
+    }
+}
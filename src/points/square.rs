@@ -0,0 +1,148 @@
+use core::ops::RangeInclusive;
+
+use glam::{ivec2, IVec2};
+
+use super::Shape;
+
+/// The points at a Chebyshev distance of at most `radius`, see [`super::square`].
+#[derive(Debug, Clone)]
+pub struct Square {
+    radius: i32,
+    dx: i32,
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+impl Square {
+    #[inline]
+    pub fn new(radius: i32) -> Self {
+        let mut x = -radius..=radius;
+        let dx = x.next().unwrap_or_default();
+
+        Self {
+            radius,
+            dx,
+            x,
+            y: -radius..=radius,
+        }
+    }
+}
+
+impl Iterator for Square {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.y.next() {
+            Some(y) => Some(ivec2(self.dx, y)),
+            None => {
+                self.dx = self.x.next()?;
+                self.y = -self.radius..=self.radius;
+                self.y.next().map(|y| ivec2(self.dx, y))
+            }
+        }
+    }
+}
+
+impl Shape for Square {
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        offset.abs().max_element().try_into().unwrap()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The points at a Chebyshev distance of exactly `radius`, see [`super::square_ring`].
+#[derive(Debug, Clone)]
+pub struct SquareRing {
+    radius: i32,
+    dx: i32,
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+impl SquareRing {
+    #[inline]
+    pub fn new(radius: i32) -> Self {
+        let mut x = -radius..=radius;
+        let dx = x.next().unwrap_or_default();
+
+        Self {
+            radius,
+            dx,
+            x,
+            y: -radius..=radius,
+        }
+    }
+}
+
+impl Iterator for SquareRing {
+    type Item = IVec2;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.y.next() {
+                Some(y) => {
+                    // Inside the left and right sides, only the top and bottom points are on the ring.
+                    if self.dx.abs() == self.radius || y.abs() == self.radius {
+                        return Some(ivec2(self.dx, y));
+                    }
+                }
+                None => {
+                    self.dx = self.x.next()?;
+                    self.y = -self.radius..=self.radius;
+                }
+            }
+        }
+    }
+}
+
+impl Shape for SquareRing {
+    #[inline]
+    fn distance(offset: IVec2) -> u32 {
+        Square::distance(offset)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use glam::ivec2;
+
+    use super::{Shape, Square, SquareRing};
+
+    #[test]
+    fn yields_the_points_of_the_square() {
+        assert_eq!(Square::new(0).collect::<Vec<_>>(), [ivec2(0, 0)]);
+        assert_eq!(Square::new(2).count(), 25);
+        assert_eq!(Square::new(-1).count(), 0);
+    }
+
+    #[test]
+    fn yields_8r_points_on_the_ring() {
+        for radius in 1..=5 {
+            let points = SquareRing::new(radius).collect::<HashSet<_>>();
+
+            assert_eq!(points.len(), (8 * radius).try_into().unwrap());
+            assert!(points
+                .iter()
+                .all(|&p| SquareRing::distance(p) == radius.try_into().unwrap()));
+        }
+    }
+
+    #[test]
+    fn yields_the_origin_for_a_zero_radius_ring() {
+        assert_eq!(SquareRing::new(0).collect::<Vec<_>>(), [ivec2(0, 0)]);
+    }
+
+    #[test]
+    fn is_empty_for_negative_radius() {
+        assert_eq!(SquareRing::new(-1).count(), 0);
+        assert_eq!(SquareRing::new(-3).count(), 0);
+    }
+}
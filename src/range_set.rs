@@ -0,0 +1,281 @@
+use core::ops::Range;
+use std::collections::{BTreeMap, BTreeSet};
+
+use num::Integer;
+
+/// A set of values stored as disjoint ranges, adjacent ranges are merged.
+///
+/// The ranges are also indexed by length to find the first one that fits quickly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    /// The end of each range, by start.
+    ranges: BTreeMap<T, T>,
+    /// The starts of the ranges, by length.
+    starts_by_length: BTreeMap<T, BTreeSet<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+            starts_by_length: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Integer + Copy> RangeSet<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of disjoint ranges.
+    #[inline]
+    pub fn ranges_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn total_length(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |acc, (&start, &end)| acc + end - start)
+    }
+
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    /// Returns the range of the set that contains `value`.
+    #[inline]
+    pub fn range_containing(&self, value: T) -> Option<Range<T>> {
+        let (&start, &end) = self.ranges.range(..=value).next_back()?;
+        (value < end).then_some(start..end)
+    }
+
+    /// Iterates over the disjoint ranges, in increasing order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + Clone + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..end)
+    }
+
+    /// Returns the first range, in increasing order, that has at least `length` values.
+    ///
+    /// It compares the first range of each distinct length that is long enough,
+    /// so it takes `O(L)` where `L` is the number of such lengths, not the number of ranges.
+    pub fn first_fit(&self, length: T) -> Option<Range<T>> {
+        let start = self
+            .starts_by_length
+            .range(length..)
+            .filter_map(|(_, starts)| starts.first())
+            .min()?;
+        Some(*start..self.ranges[start])
+    }
+
+    /// Adds the values of `range`, merging it with the overlapping and adjacent ranges.
+    pub fn insert(&mut self, range: Range<T>) {
+        let Range { mut start, mut end } = range;
+        if start >= end {
+            return;
+        }
+
+        if let Some((&previous_start, &previous_end)) = self.ranges.range(..start).next_back() {
+            if previous_end >= start {
+                start = previous_start;
+                end = end.max(previous_end);
+                self.remove_range(previous_start);
+            }
+        }
+
+        let merged = self
+            .ranges
+            .range(start..=end)
+            .map(|(&start, &end)| (start, end))
+            .collect::<Vec<_>>();
+        for (merged_start, merged_end) in merged {
+            end = end.max(merged_end);
+            self.remove_range(merged_start);
+        }
+
+        self.insert_range(start, end);
+    }
+
+    /// Removes the values of `range`, splitting the ranges that it overlaps partially.
+    pub fn remove(&mut self, range: Range<T>) {
+        let Range { start, end } = range;
+        if start >= end {
+            return;
+        }
+
+        let previous = self
+            .ranges
+            .range(..start)
+            .next_back()
+            .filter(|(_, &previous_end)| previous_end > start);
+        let overlapping = previous
+            .into_iter()
+            .chain(self.ranges.range(start..end))
+            .map(|(&start, &end)| (start, end))
+            .collect::<Vec<_>>();
+
+        for (overlapping_start, overlapping_end) in overlapping {
+            self.remove_range(overlapping_start);
+            if overlapping_start < start {
+                self.insert_range(overlapping_start, start);
+            }
+            if overlapping_end > end {
+                self.insert_range(end, overlapping_end);
+            }
+        }
+    }
+
+    /// Splits the set in two at `at`, returning the values after it, `at` included.
+    pub fn split_off(&mut self, at: T) -> Self {
+        let mut after = Self::new();
+
+        if let Some(range) = self.range_containing(at) {
+            if range.start < at {
+                self.remove(at..range.end);
+                after.insert(at..range.end);
+            }
+        }
+
+        let moved = self
+            .ranges
+            .range(at..)
+            .map(|(&start, &end)| (start, end))
+            .collect::<Vec<_>>();
+        for (start, end) in moved {
+            self.remove_range(start);
+            after.insert_range(start, end);
+        }
+
+        after
+    }
+
+    /// Inserts a range that doesn't overlap nor touch any other.
+    fn insert_range(&mut self, start: T, end: T) {
+        self.ranges.insert(start, end);
+        self.starts_by_length
+            .entry(end - start)
+            .or_default()
+            .insert(start);
+    }
+
+    fn remove_range(&mut self, start: T) {
+        let end = self.ranges.remove(&start).expect("no range at this start");
+        let length = end - start;
+        let starts = self.starts_by_length.get_mut(&length).unwrap();
+        starts.remove(&start);
+        if starts.is_empty() {
+            self.starts_by_length.remove(&length);
+        }
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer + Copy> Extend<Range<T>> for RangeSet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+
+    fn ranges(set: &RangeSet<u32>) -> Vec<(u32, u32)> {
+        set.iter().map(|range| (range.start, range.end)).collect()
+    }
+
+    #[test]
+    fn merges_inserted_ranges() {
+        let mut set = RangeSet::new();
+        set.insert(10..12);
+        set.insert(0..2);
+        set.insert(5..5);
+        assert_eq!(ranges(&set), [(0, 2), (10, 12)]);
+
+        // Adjacent ranges are merged.
+        set.insert(2..4);
+        set.insert(8..10);
+        assert_eq!(ranges(&set), [(0, 4), (8, 12)]);
+
+        // A range overlapping several ones merges them all.
+        set.insert(3..9);
+        assert_eq!(ranges(&set), [(0, 12)]);
+        set.insert(1..5);
+        assert_eq!(ranges(&set), [(0, 12)]);
+
+        assert_eq!(set.total_length(), 12);
+        assert_eq!(set.first_fit(12), Some(0..12));
+        assert_eq!(set.first_fit(13), None);
+    }
+
+    #[test]
+    fn splits_removed_ranges() {
+        let mut set = [0..10, 20..30].into_iter().collect::<RangeSet<u32>>();
+
+        set.remove(3..5);
+        assert_eq!(ranges(&set), [(0, 3), (5, 10), (20, 30)]);
+
+        set.remove(8..25);
+        assert_eq!(ranges(&set), [(0, 3), (5, 8), (25, 30)]);
+
+        set.remove(0..3);
+        set.remove(28..40);
+        assert_eq!(ranges(&set), [(5, 8), (25, 28)]);
+
+        assert!(set.contains(5));
+        assert!(!set.contains(8));
+        assert_eq!(set.range_containing(26), Some(25..28));
+        assert_eq!(set.first_fit(3), Some(5..8));
+        assert_eq!(set.first_fit(4), None);
+    }
+
+    #[test]
+    fn finds_the_first_fit() {
+        let mut set = [0..1, 5..9, 10..12, 20..30, 40..44]
+            .into_iter()
+            .collect::<RangeSet<u32>>();
+
+        assert_eq!(set.first_fit(1), Some(0..1));
+        assert_eq!(set.first_fit(2), Some(5..9));
+        assert_eq!(set.first_fit(5), Some(20..30));
+        assert_eq!(set.first_fit(11), None);
+
+        // The length index follows the removals.
+        set.remove(5..7);
+        assert_eq!(set.first_fit(3), Some(20..30));
+        assert_eq!(set.first_fit(2), Some(7..9));
+    }
+
+    #[test]
+    fn splits_off() {
+        let mut set = [0..10, 20..30].into_iter().collect::<RangeSet<u32>>();
+        let after = set.split_off(5);
+        assert_eq!(ranges(&set), [(0, 5)]);
+        assert_eq!(ranges(&after), [(5, 10), (20, 30)]);
+        assert_eq!(after.first_fit(6), Some(20..30));
+    }
+}
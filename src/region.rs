@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use crate::array2d::{self, Array2D};
+use crate::union_find::connected_components;
+
+/// `(row, column)` offsets of the four neighbors, clockwise from up.
+const NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// `(row, column)` offsets of the four diagonal neighbors, clockwise from up-left.
+const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, 1), (1, -1)];
+
+/// A set of `(row, column)` cells.
+///
+/// Cells are considered adjacent to their orthogonal neighbors only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<(usize, usize)>,
+}
+
+/// The smallest rectangle that contains a [`Region`], bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    /// The `(row, column)` of the top-left corner.
+    pub min: (usize, usize),
+    /// The `(row, column)` of the bottom-right corner.
+    pub max: (usize, usize),
+}
+
+impl BoundingBox {
+    #[inline]
+    pub fn size(&self) -> array2d::Size {
+        array2d::Size::from_rows_columns(self.max.0 - self.min.0 + 1, self.max.1 - self.min.1 + 1)
+    }
+
+    #[inline]
+    pub fn contains(&self, (row, column): (usize, usize)) -> bool {
+        (self.min.0..=self.max.0).contains(&row) && (self.min.1..=self.max.1).contains(&column)
+    }
+}
+
+impl Region {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn cells(&self) -> &HashSet<(usize, usize)> {
+        &self.cells
+    }
+
+    #[inline]
+    pub fn into_cells(self) -> HashSet<(usize, usize)> {
+        self.cells
+    }
+
+    #[inline]
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        self.cells.contains(&cell)
+    }
+
+    #[inline]
+    pub fn insert(&mut self, cell: (usize, usize)) -> bool {
+        self.cells.insert(cell)
+    }
+
+    /// The number of cells.
+    #[inline]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges between the region and the outside, holes included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&cell| {
+                NEIGHBORS
+                    .iter()
+                    .filter(|&&offset| !self.contains_offset(cell, offset))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The number of straight sides of the region, holes included.
+    ///
+    /// A polygon has as many sides as corners, so this counts the corners.
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&cell| {
+                DIAGONALS
+                    .iter()
+                    .filter(|&&(d_row, d_column)| {
+                        let vertical = self.contains_offset(cell, (d_row, 0));
+                        let horizontal = self.contains_offset(cell, (0, d_column));
+                        let diagonal = self.contains_offset(cell, (d_row, d_column));
+
+                        // convex corner || concave corner
+                        (!vertical && !horizontal) || (vertical && horizontal && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns the bounding box of the region, or [`None`] if it is empty.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut cells = self.cells.iter().copied();
+        let first = cells.next()?;
+
+        Some(cells.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bb, (row, column)| BoundingBox {
+                min: (bb.min.0.min(row), bb.min.1.min(column)),
+                max: (bb.max.0.max(row), bb.max.1.max(column)),
+            },
+        ))
+    }
+
+    /// Returns the holes of the region: the groups of cells outside of it
+    /// that are enclosed by the region.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some(bounding_box) = self.bounding_box() else {
+            return vec![];
+        };
+
+        let (min_row, min_column) = bounding_box.min;
+        let inside = Array2D::from_elem(bounding_box.size(), |row, column| {
+            self.contains((min_row + row, min_column + column))
+        });
+
+        let components = connected_components(&inside, |a, b| a == b);
+
+        let size = inside.size();
+        let mut is_hole = vec![true; components.count()];
+        for ((row, column), &label) in components.labels().iter_with_index() {
+            let on_border = row == 0
+                || column == 0
+                || row + 1 == size.rows_count()
+                || column + 1 == size.columns_count();
+
+            if inside[(row, column)] || on_border {
+                is_hole[label] = false;
+            }
+        }
+
+        components
+            .positions()
+            .into_iter()
+            .zip(is_hole)
+            .filter(|(_, is_hole)| *is_hole)
+            .map(|(cells, _)| {
+                cells
+                    .into_iter()
+                    .map(|(row, column)| (min_row + row, min_column + column))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn contains_offset(
+        &self,
+        (row, column): (usize, usize),
+        (d_row, d_column): (isize, isize),
+    ) -> bool {
+        row.checked_add_signed(d_row)
+            .zip(column.checked_add_signed(d_column))
+            .is_some_and(|cell| self.contains(cell))
+    }
+}
+
+impl From<HashSet<(usize, usize)>> for Region {
+    #[inline]
+    fn from(cells: HashSet<(usize, usize)>) -> Self {
+        Self { cells }
+    }
+}
+
+impl FromIterator<(usize, usize)> for Region {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<(usize, usize)> for Region {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
@@ -0,0 +1,187 @@
+//! Parsing of structured lines with a pattern, see [`scan!`](crate::scan!).
+
+use core::fmt;
+use core::str::FromStr;
+
+const PLACEHOLDER: &str = "{}";
+
+/// Parses `input` against a pattern where each `{}` is a value,
+/// and returns the values as a tuple of the given types.
+///
+/// The number of `{}` is checked against the number of types at compile time,
+/// and two `{}` must be separated by some text.
+///
+/// Returns a [`ScanError`] naming the line and the column of the mismatch.
+///
+/// ```
+/// use advent_of_code::scan;
+///
+/// let (x, y) = scan!("Button A: X+94, Y+34", "Button A: X+{}, Y+{}" => (i32, i32)).unwrap();
+/// assert_eq!((x, y), (94, 34));
+///
+/// let a = scan!("Register A: 729", "Register A: {}" => u64).unwrap();
+/// assert_eq!(a, 729);
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal => ($($T:ty),+ $(,)?)) => {{
+        const _: () = $crate::scan::check_pattern($pattern, 0 $(+ {
+            let _ = ::core::marker::PhantomData::<$T>;
+            1
+        })+);
+
+        (|| -> ::core::result::Result<($($T,)+), $crate::scan::ScanError> {
+            let mut fields = $crate::scan::Fields::new($input, $pattern)?;
+            Ok(($( fields.parse::<$T>()?, )+))
+        })()
+    }};
+
+    ($input:expr, $pattern:literal => $T:ty) => {
+        $crate::scan!($input, $pattern => ($T,)).map(|(value,)| value)
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The error returned by [`scan!`](crate::scan!).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    pub line: String,
+    /// The column of the mismatch, starting at 1.
+    pub column: usize,
+    pub kind: ScanErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    /// The line doesn't contain the text of the pattern.
+    Mismatch { expected: &'static str },
+    /// A value cannot be parsed.
+    InvalidValue {
+        value: String,
+        type_name: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line `{}`, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ScanErrorKind::Mismatch { expected } => write!(f, "expected `{expected}`"),
+            ScanErrorKind::InvalidValue {
+                value,
+                type_name,
+                message,
+            } => write!(f, "cannot parse `{value}` as `{type_name}`: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// Asserts that `pattern` has `count` placeholders that aren't next to each other.
+#[doc(hidden)]
+pub const fn check_pattern(pattern: &str, count: usize) {
+    let bytes = pattern.as_bytes();
+    let mut found = 0;
+    let mut previous_end = None;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            if let Some(end) = previous_end {
+                assert!(end != i, "placeholders must be separated by some text");
+            }
+            found += 1;
+            previous_end = Some(i + 2);
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    assert!(
+        found == count,
+        "the number of `{{}}` doesn't match the number of types"
+    );
+}
+
+/// The values of a line being scanned.
+#[doc(hidden)]
+pub struct Fields<'a> {
+    line: &'a str,
+    /// The part of the line that remains to be scanned.
+    rest: &'a str,
+    /// The part of the pattern after the current placeholder.
+    pattern: &'static str,
+}
+
+impl<'a> Fields<'a> {
+    pub fn new(line: &'a str, pattern: &'static str) -> Result<Self, ScanError> {
+        let (prefix, pattern) = pattern.split_once(PLACEHOLDER).unwrap_or((pattern, ""));
+
+        let fields = Self {
+            line,
+            rest: line,
+            pattern,
+        };
+
+        let rest = line
+            .strip_prefix(prefix)
+            .ok_or_else(|| fields.mismatch(prefix))?;
+
+        Ok(Self { rest, ..fields })
+    }
+
+    pub fn parse<T: FromStr>(&mut self) -> Result<T, ScanError>
+    where
+        T::Err: fmt::Display,
+    {
+        let value = match self.pattern.split_once(PLACEHOLDER) {
+            Some((separator, pattern)) => {
+                let (value, rest) = self
+                    .rest
+                    .split_once(separator)
+                    .ok_or_else(|| self.mismatch(separator))?;
+                self.pattern = pattern;
+                self.rest = rest;
+                value
+            }
+            None => {
+                let value = self.rest.strip_suffix(self.pattern).ok_or_else(|| {
+                    // Point at the end of the line, where the suffix was expected.
+                    let mut error = self.mismatch(self.pattern);
+                    error.column = self.line.len() + 1;
+                    error
+                })?;
+                self.rest = "";
+                value
+            }
+        };
+
+        value.parse().map_err(|err: T::Err| ScanError {
+            line: self.line.to_string(),
+            column: self.column_of(value),
+            kind: ScanErrorKind::InvalidValue {
+                value: value.to_string(),
+                type_name: core::any::type_name::<T>(),
+                message: err.to_string(),
+            },
+        })
+    }
+
+    fn column_of(&self, s: &str) -> usize {
+        s.as_ptr() as usize - self.line.as_ptr() as usize + 1
+    }
+
+    fn mismatch(&self, expected: &'static str) -> ScanError {
+        ScanError {
+            line: self.line.to_string(),
+            column: self.column_of(self.rest),
+            kind: ScanErrorKind::Mismatch { expected },
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+/// The distances and predecessors of the states reached by a search.
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    distances: HashMap<S, C>,
+    /// The predecessors of a state on all its shortest paths.
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Returns the distance from the closest source to `state`,
+    /// or [`None`] if it has not been reached.
+    #[inline]
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    #[inline]
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    #[inline]
+    pub fn into_distances(self) -> HashMap<S, C> {
+        self.distances
+    }
+
+    /// Returns the predecessors of `state` on its shortest paths.
+    #[inline]
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns one of the shortest paths from a source to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Returns every state that is on a shortest path from a source to `state`, both included.
+    pub fn states_on_paths_to(&self, state: &S) -> HashSet<S> {
+        let mut states = HashSet::new();
+
+        if !self.distances.contains_key(state) {
+            return states;
+        }
+
+        let mut to_visit = vec![state.clone()];
+        while let Some(state) = to_visit.pop() {
+            if states.insert(state.clone()) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        states
+    }
+
+    /// Returns all the shortest paths from a source to `state`, both included.
+    ///
+    /// The paths never visit a state twice, so zero-cost cycles are skipped.
+    ///
+    /// Note: the number of paths may grow exponentially, prefer
+    /// [`Paths::states_on_paths_to`] when only the states are needed.
+    pub fn all_paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return vec![];
+        }
+
+        let mut paths = vec![];
+        let mut to_visit = vec![vec![state.clone()]];
+
+        while let Some(path) = to_visit.pop() {
+            let predecessors = self.predecessors(path.last().unwrap());
+
+            if predecessors.is_empty() {
+                let mut path = path;
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+
+            for previous in predecessors {
+                if path.contains(previous) {
+                    continue;
+                }
+
+                let mut path = path.clone();
+                path.push(previous.clone());
+                to_visit.push(path);
+            }
+        }
+
+        paths
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the distances from `sources` to every reachable state,
+/// where each move costs `1`.
+pub fn bfs_all<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut to_explore = VecDeque::new();
+
+    for source in sources {
+        if paths.distances.insert(source.clone(), 0).is_none() {
+            to_explore.push_back(source);
+        }
+    }
+
+    while let Some(state) = to_explore.pop_front() {
+        let distance = paths.distances[&state] + 1;
+
+        for next in successors(&state) {
+            match paths.distances.get(&next) {
+                None => {
+                    paths.distances.insert(next.clone(), distance);
+                    paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                    to_explore.push_back(next);
+                }
+                Some(&d) if d == distance => {
+                    // NOTE: sources have no predecessor.
+                    if let Some(predecessors) = paths.predecessors.get_mut(&next) {
+                        predecessors.push(state.clone());
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    paths
+}
+
+/// Finds a shortest path from `start` to a state for which `is_goal` returns `true`,
+/// where each move costs `1`.
+///
+/// Returns the path, `start` and the goal included.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    if is_goal(&start) {
+        return Some(vec![start]);
+    }
+
+    let mut paths = Paths::<S, usize>::new();
+    let mut to_explore = VecDeque::new();
+
+    paths.distances.insert(start.clone(), 0);
+    to_explore.push_back(start);
+
+    while let Some(state) = to_explore.pop_front() {
+        let distance = paths.distances[&state] + 1;
+
+        for next in successors(&state) {
+            if paths.distances.contains_key(&next) {
+                continue;
+            }
+
+            paths.distances.insert(next.clone(), distance);
+            paths.predecessors.insert(next.clone(), vec![state.clone()]);
+
+            if is_goal(&next) {
+                return paths.path_to(&next);
+            }
+
+            to_explore.push_back(next);
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Computes the distances from `sources` to every reachable state,
+/// `successors` yields the next states with the cost to move to them.
+pub fn dijkstra_all<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(sources, successors, |_| C::zero(), |_| false).0
+}
+
+/// Finds a shortest path from `start` to a state for which `is_goal` returns `true`,
+/// `successors` yields the next states with the cost to move to them.
+///
+/// Returns the path, `start` and the goal included, and its cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// Finds a shortest path from `start` to a state for which `is_goal` returns `true`,
+/// `successors` yields the next states with the cost to move to them.
+///
+/// `heuristic` estimates the cost from a state to the goal, it must never overestimate it.
+///
+/// Returns the path, `start` and the goal included, and its cost.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (paths, goal) = search([start], successors, heuristic, is_goal);
+    let goal = goal?;
+    let cost = paths.distance(&goal)?;
+    Some((paths.path_to(&goal)?, cost))
+}
+
+/// An item of the priority queue, ordered by lowest priority first.
+struct Candidate<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Candidate<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Candidate<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Candidate<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Candidate<S, C> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// A* search which records every shortest path until a goal is reached,
+/// including the paths to the goal found after it is reached.
+///
+/// Returns the paths and the goal, if any.
+fn search<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Paths<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new();
+    let mut to_explore = BinaryHeap::new();
+
+    for source in sources {
+        if paths.distances.insert(source.clone(), C::zero()).is_none() {
+            to_explore.push(Candidate {
+                priority: heuristic(&source),
+                cost: C::zero(),
+                state: source,
+            });
+        }
+    }
+
+    let mut goal: Option<(S, C)> = None;
+
+    while let Some(Candidate {
+        priority,
+        cost,
+        state,
+    }) = to_explore.pop()
+    {
+        if goal
+            .as_ref()
+            .is_some_and(|&(_, goal_cost)| priority > goal_cost)
+        {
+            // Every state that can be on a shortest path to the goal has been settled.
+            break;
+        }
+
+        if paths.distances[&state] < cost {
+            // A shorter path to this state has already been explored.
+            continue;
+        }
+
+        if goal.is_none() && is_goal(&state) {
+            // NOTE: keep exploring the states at the same cost, as they may be
+            // other predecessors of the goal through zero-cost moves.
+            goal = Some((state, cost));
+            continue;
+        }
+
+        for (next, move_cost) in successors(&state) {
+            let next_cost = cost + move_cost;
+
+            match paths.distances.get(&next) {
+                Some(&d) if d < next_cost => {}
+                Some(&d) if d == next_cost => {
+                    // NOTE: sources have no predecessor.
+                    if let Some(predecessors) = paths.predecessors.get_mut(&next) {
+                        predecessors.push(state.clone());
+                    }
+                }
+                _ => {
+                    paths.distances.insert(next.clone(), next_cost);
+                    paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                    to_explore.push(Candidate {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    (paths, goal.map(|(goal, _)| goal))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{dijkstra_all, search};
+
+    #[test]
+    fn finds_tied_predecessors_of_the_goal() {
+        // Both `'s' -> 'g'` and `'s' -> 'x' -> 'g'` cost 1.
+        let successors = |state: &char| match state {
+            's' => vec![('g', 1), ('x', 1)],
+            'x' => vec![('g', 0)],
+            _ => vec![],
+        };
+
+        let (paths, goal) = search(['s'], successors, |_| 0, |&state| state == 'g');
+        assert_eq!(goal, Some('g'));
+        assert_eq!(paths.distance(&'g'), Some(1));
+
+        let mut all_paths = paths.all_paths_to(&'g');
+        all_paths.sort_unstable();
+        assert_eq!(all_paths, [vec!['s', 'g'], vec!['s', 'x', 'g']]);
+    }
+
+    #[test]
+    fn skips_zero_cost_cycles() {
+        // `'a'` and `'b'` are connected both ways by zero-cost moves.
+        let successors = |state: &char| match state {
+            's' => vec![('a', 1)],
+            'a' => vec![('b', 0), ('g', 1)],
+            'b' => vec![('a', 0), ('g', 1)],
+            _ => vec![],
+        };
+
+        let paths = dijkstra_all(['s'], successors);
+        assert_eq!(paths.distance(&'g'), Some(2));
+
+        let mut all_paths = paths.all_paths_to(&'g');
+        all_paths.sort_unstable();
+        assert_eq!(all_paths, [vec!['s', 'a', 'b', 'g'], vec!['s', 'a', 'g']]);
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use glam::{ivec2, IVec2};
+
+use crate::array2d::{Array2D, Size};
+use crate::{grid, Dir, Dir8};
+
+/// An unbounded grid that only stores the cells that have a value.
+///
+/// Positions are `(x, y)` where the positive directions are right for X axis and down for Y axis.
+/// The bounds of the cells are kept up to date on insertion and removal,
+/// see [`SparseGrid::remove`] for its cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IVec2, T>,
+    /// The top-left and bottom-right corners, included.
+    bounds: Option<(IVec2, IVec2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an ascii map, the cells for which `f` returns [`None`] are empty.
+    pub fn parse_ascii(input: &str, mut f: impl FnMut(u8) -> Option<T>) -> Self {
+        grid::ascii_cells(input)
+            .filter_map(|(pos, c)| Some((pos, f(c)?)))
+            .collect()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    #[inline]
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets the value of `pos`, returning the previous one.
+    #[inline]
+    pub fn insert(&mut self, pos: IVec2, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(pos), max.max(pos)),
+            None => (pos, pos),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    /// Empties `pos`, returning its value.
+    ///
+    /// Removing a cell on the border of the bounds rescans all the cells
+    /// to shrink the bounds, which is O(n) in the number of cells.
+    pub fn remove(&mut self, pos: IVec2) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+
+        // The bounds only change when a cell on the border is removed.
+        if let Some((min, max)) = self.bounds {
+            if pos.cmpeq(min).any() || pos.cmpeq(max).any() {
+                self.bounds = self.compute_bounds();
+            }
+        }
+
+        Some(value)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    fn compute_bounds(&self) -> Option<(IVec2, IVec2)> {
+        let mut positions = self.cells.keys().copied();
+        let first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (min.min(pos), max.max(pos))
+        }))
+    }
+
+    /// Returns the top-left and bottom-right corners of the cells, included,
+    /// or [`None`] if the grid is empty.
+    #[inline]
+    pub fn bounds(&self) -> Option<(IVec2, IVec2)> {
+        self.bounds
+    }
+
+    /// Returns the width and height of the bounds.
+    #[inline]
+    pub fn size(&self) -> IVec2 {
+        self.bounds
+            .map_or(IVec2::ZERO, |(min, max)| max - min + IVec2::ONE)
+    }
+
+    /// Iterates over the cells in an arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> + Clone + '_ {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (IVec2, &mut T)> + '_ {
+        self.cells.iter_mut().map(|(&pos, value)| (pos, value))
+    }
+
+    #[inline]
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> + Clone + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Iterates over the four orthogonal neighbors of `pos` that have a value, clockwise from up.
+    #[inline]
+    pub fn neighbors4(&self, pos: IVec2) -> impl Iterator<Item = (IVec2, &T)> + Clone + '_ {
+        Dir::ALL.into_iter().filter_map(move |dir| {
+            let pos = pos + dir.as_vec_down_right();
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// Iterates over the eight neighbors of `pos` that have a value, clockwise from up.
+    #[inline]
+    pub fn neighbors8(&self, pos: IVec2) -> impl Iterator<Item = (IVec2, &T)> + Clone + '_ {
+        Dir8::ALL.into_iter().filter_map(move |dir| {
+            let pos = pos + dir.as_vec_down_right();
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// Renders the cells within the bounds, one line per row, mapping each cell with `f`.
+    ///
+    /// Useful to print the grid while debugging.
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        let mut output = String::new();
+        for y in min.y..=max.y {
+            if y != min.y {
+                output.push('\n');
+            }
+            output.extend((min.x..=max.x).map(|x| f(self.get(ivec2(x, y)))));
+        }
+        output
+    }
+
+    /// Converts to a dense array covering the bounds, the empty cells are `default`.
+    ///
+    /// The top-left corner of the bounds is at `(0, 0)` in the array.
+    pub fn to_array2d(&self, default: T) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        let min = self.bounds.map_or(IVec2::ZERO, |(min, _)| min);
+        let size = self.size();
+        let size = Size::from_rows_columns(size.y.try_into().unwrap(), size.x.try_into().unwrap());
+
+        Array2D::from_elem(size, |row, column| {
+            let pos = min + ivec2(column.try_into().unwrap(), row.try_into().unwrap());
+            self.get(pos).cloned().unwrap_or_else(|| default.clone())
+        })
+    }
+
+    /// Creates a grid from the cells of `array` for which `f` returns a value,
+    /// the cell at `(row, column)` is at `(column, row)`.
+    pub fn from_array2d<U>(array: &Array2D<U>, mut f: impl FnMut(&U) -> Option<T>) -> Self {
+        array
+            .iter_with_index()
+            .filter_map(|((row, column), value)| {
+                let pos = ivec2(column.try_into().unwrap(), row.try_into().unwrap());
+                Some((pos, f(value)?))
+            })
+            .collect()
+    }
+}
+
+impl<T> From<Array2D<T>> for SparseGrid<T> {
+    /// Keeps all the cells, the cell at `(row, column)` is at `(column, row)`.
+    fn from(array: Array2D<T>) -> Self {
+        array
+            .into_iter_with_index()
+            .map(|((row, column), value)| {
+                let pos = ivec2(column.try_into().unwrap(), row.try_into().unwrap());
+                (pos, value)
+            })
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(IVec2, T)> for SparseGrid<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (IVec2, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(IVec2, T)> for SparseGrid<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (IVec2, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use glam::{ivec2, IVec2};
+
+    use super::SparseGrid;
+
+    fn render(grid: &SparseGrid<u8>) -> String {
+        grid.render(|cell| cell.map_or('.', |&c| c.into()))
+    }
+
+    #[test]
+    fn updates_the_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.size(), IVec2::ZERO);
+
+        grid.insert(ivec2(1, 2), 'a');
+        assert_eq!(grid.bounds(), Some((ivec2(1, 2), ivec2(1, 2))));
+
+        grid.insert(ivec2(-3, 4), 'b');
+        grid.insert(ivec2(0, 0), 'c');
+        assert_eq!(grid.bounds(), Some((ivec2(-3, 0), ivec2(1, 4))));
+        assert_eq!(grid.size(), ivec2(5, 5));
+
+        // Inside the bounds, they don't change.
+        grid.insert(ivec2(-1, 1), 'd');
+        assert_eq!(grid.remove(ivec2(-1, 1)), Some('d'));
+        assert_eq!(grid.bounds(), Some((ivec2(-3, 0), ivec2(1, 4))));
+
+        // On the border, they shrink.
+        assert_eq!(grid.remove(ivec2(-3, 4)), Some('b'));
+        assert_eq!(grid.bounds(), Some((ivec2(0, 0), ivec2(1, 2))));
+        assert_eq!(grid.remove(ivec2(-3, 4)), None);
+
+        grid.remove(ivec2(1, 2));
+        grid.remove(ivec2(0, 0));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn renders_the_bounds() {
+        let mut grid = SparseGrid::parse_ascii("..#\n#..\n...", |c| (c == b'#').then_some(c));
+        assert_eq!(render(&grid), "..#\n#..");
+
+        grid.insert(ivec2(-1, 3), b'@');
+        assert_eq!(render(&grid), "...#\n.#..\n....\n@...");
+        assert_eq!(render(&SparseGrid::new()), "");
+    }
+
+    #[test]
+    fn yields_the_neighbors_with_a_value() {
+        let grid = SparseGrid::parse_ascii("ab.\n.cd\ne..", |c| (c != b'.').then_some(c));
+
+        assert_eq!(
+            grid.neighbors4(ivec2(1, 1)).collect::<Vec<_>>(),
+            [(ivec2(1, 0), &b'b'), (ivec2(2, 1), &b'd')]
+        );
+        assert_eq!(
+            grid.neighbors8(ivec2(1, 1)).collect::<Vec<_>>(),
+            [
+                (ivec2(1, 0), &b'b'),
+                (ivec2(2, 1), &b'd'),
+                (ivec2(0, 2), &b'e'),
+                (ivec2(0, 0), &b'a')
+            ]
+        );
+        assert_eq!(grid.neighbors4(ivec2(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn converts_to_and_from_arrays() {
+        let mut grid = SparseGrid::new();
+        grid.insert(ivec2(-2, 1), b'a');
+        grid.insert(ivec2(0, 3), b'b');
+
+        let array = grid.to_array2d(b'.');
+        assert_eq!(array.render(|&c| c.into()), "a..\n...\n..b");
+
+        // The top-left corner of the bounds moves to the origin.
+        let expected = grid
+            .iter()
+            .map(|(pos, &c)| (pos - ivec2(-2, 1), c))
+            .collect::<SparseGrid<_>>();
+        let round_trip = SparseGrid::from_array2d(&array, |&c| (c != b'.').then_some(c));
+        assert_eq!(round_trip, expected);
+
+        let all = SparseGrid::from(array);
+        assert_eq!(all.len(), 9);
+        assert_eq!(render(&all), "a..\n...\n..b");
+    }
+}
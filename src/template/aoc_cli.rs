@@ -46,7 +46,7 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args, false)
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
@@ -65,7 +65,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(&args, false)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
@@ -77,7 +77,14 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // NOTE: the output is captured to know whether the answer has been accepted.
+    call_aoc_cli(&args, true)
+}
+
+/// Returns `true` if the output of [`submit`] reports a right answer.
+pub fn is_answer_accepted(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("That's the right answer")
 }
 
 fn get_input_path(day: Day) -> String {
@@ -88,7 +95,7 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -108,15 +115,27 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// Calls aoc-cli, its stdout is kept in the returned [`Output`] if `capture_stdout` is set,
+/// and is printed either way.
+fn call_aoc_cli(args: &[String], capture_stdout: bool) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
+    let stdout = if capture_stdout {
+        Stdio::piped()
+    } else {
+        Stdio::inherit()
+    };
+
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
+    if capture_stdout {
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+    }
+
     if output.status.success() {
         Ok(output)
     } else {
@@ -1,5 +1,12 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::run_multi::run_multi;
+use crate::template::timings::Timings;
+use crate::template::{all_days, DaySet};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(days: Option<DaySet>, is_release: bool) {
+    let days_to_run = match days {
+        Some(days) => days.resolve(&Timings::read_from_file()),
+        None => all_days().collect(),
+    };
+
+    run_multi(&days_to_run, is_release, false);
 }
@@ -3,4 +3,6 @@ pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod stats;
 pub mod time;
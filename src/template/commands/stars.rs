@@ -0,0 +1,15 @@
+use crate::template::readme_stars;
+use crate::template::submissions::Submissions;
+
+pub fn handle() {
+    let submissions = Submissions::read_from_file();
+
+    match readme_stars::update(&submissions) {
+        Ok(()) => {
+            println!("Stored updated stars.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated stars.");
+        }
+    }
+}
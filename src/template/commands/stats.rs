@@ -0,0 +1,13 @@
+use crate::template::submissions::Submissions;
+use crate::template::timings::Timings;
+use crate::template::{aoc_cli, readme_stars, stats};
+
+pub fn handle() {
+    let timings = Timings::read_from_file();
+    let submissions = Submissions::read_from_file();
+    // NOTE: the days solved before submissions were recorded only have a star in the README.
+    let readme_stars = readme_stars::read();
+
+    let stats = stats::collect(&timings, &submissions, &readme_stars, aoc_cli::get_year());
+    stats::print(&stats);
+}
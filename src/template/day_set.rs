@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use num::Integer;
+
+use crate::day;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day};
+
+/// The threshold used by the `slow` selector when none is provided.
+const DEFAULT_SLOW_THRESHOLD_NANOS: f64 = 100_000_000_f64;
+
+/// A set of days selected from the command line.
+///
+/// The set is a comma-separated list of selectors:
+/// - a single day: `7`
+/// - an inclusive range: `1-5` or `12..=15`
+/// - an exclusive range: `12..15`
+/// - `all`, `odd` or `even`
+/// - `unsolved`: the days without a stored timing for both parts, `unbenched` is an alias
+/// - `slow`: the days whose stored timing is above 100ms,
+///   or above a custom threshold with `slow>250ms`
+///
+/// ```
+/// # use advent_of_code::template::DaySet;
+/// let days: DaySet = "1-5,7,12..=15,odd".parse().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySet {
+    selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Range(Day, Day),
+    Odd,
+    Even,
+    Unsolved,
+    Slow { threshold_nanos: f64 },
+}
+
+impl DaySet {
+    /// Resolves the selectors into the set of days they match.
+    ///
+    /// `timings` are used by the `unsolved` and `slow` selectors.
+    pub fn resolve(&self, timings: &Timings) -> HashSet<Day> {
+        all_days()
+            .filter(|&day| {
+                self.selectors
+                    .iter()
+                    .any(|selector| selector.matches(day, timings))
+            })
+            .collect()
+    }
+}
+
+impl Selector {
+    fn matches(&self, day: Day, timings: &Timings) -> bool {
+        match *self {
+            Selector::Range(start, end) => start <= day && day <= end,
+            Selector::Odd => day.into_inner().is_odd(),
+            Selector::Even => day.into_inner().is_even(),
+            Selector::Unsolved => !timings.is_day_complete(&day),
+            Selector::Slow { threshold_nanos } => timings
+                .data
+                .iter()
+                .any(|t| t.day == day && t.total_nanos > threshold_nanos),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let selectors = s
+            .split(',')
+            .map(str::trim)
+            .map(parse_selector)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { selectors })
+    }
+}
+
+fn parse_selector(s: &str) -> Result<Selector, DaySetFromStrError> {
+    match s {
+        "" => Err(DaySetFromStrError::Empty),
+        "all" => Ok(Selector::Range(day!(1), day!(25))),
+        "odd" => Ok(Selector::Odd),
+        "even" => Ok(Selector::Even),
+        "unsolved" | "unbenched" => Ok(Selector::Unsolved),
+        "slow" => Ok(Selector::Slow {
+            threshold_nanos: DEFAULT_SLOW_THRESHOLD_NANOS,
+        }),
+        _ => {
+            if let Some(threshold) = s.strip_prefix("slow>") {
+                let threshold_nanos = parse_threshold(threshold)
+                    .ok_or_else(|| DaySetFromStrError::InvalidThreshold(threshold.into()))?;
+                return Ok(Selector::Slow { threshold_nanos });
+            }
+
+            if let Some((start, end)) = s.split_once("..=") {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                return range(start, end, s);
+            }
+
+            if let Some((start, end)) = s.split_once("..") {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                let end = end
+                    .into_inner()
+                    .checked_sub(1)
+                    .and_then(Day::new)
+                    .ok_or_else(|| DaySetFromStrError::InvalidRange(s.into()))?;
+                return range(start, end, s);
+            }
+
+            if let Some((start, end)) = s.split_once('-') {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                return range(start, end, s);
+            }
+
+            let day = parse_day(s)?;
+            Ok(Selector::Range(day, day))
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<Day, DaySetFromStrError> {
+    s.trim()
+        .parse()
+        .map_err(|_| DaySetFromStrError::InvalidDay(s.into()))
+}
+
+fn range(start: Day, end: Day, s: &str) -> Result<Selector, DaySetFromStrError> {
+    if start > end {
+        return Err(DaySetFromStrError::InvalidRange(s.into()));
+    }
+    Ok(Selector::Range(start, end))
+}
+
+/// Parses a duration such as `250ms` or `1.5s` into nanoseconds.
+fn parse_threshold(s: &str) -> Option<f64> {
+    let (value, factor) = if let Some(value) = s.strip_suffix("ns") {
+        (value, 1_f64)
+    } else if let Some(value) = s.strip_suffix("µs").or_else(|| s.strip_suffix("us")) {
+        (value, 1_000_f64)
+    } else if let Some(value) = s.strip_suffix("ms") {
+        (value, 1_000_000_f64)
+    } else {
+        (s.strip_suffix('s')?, 1_000_000_000_f64)
+    };
+
+    value.parse::<f64>().ok().map(|x| x * factor)
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySetFromStrError {
+    Empty,
+    InvalidDay(String),
+    InvalidRange(String),
+    InvalidThreshold(String),
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySetFromStrError::Empty => f.write_str("empty day selector"),
+            DaySetFromStrError::InvalidDay(s) => {
+                write!(
+                    f,
+                    "invalid day `{s}`, expecting a day number between 1 and 25"
+                )
+            }
+            DaySetFromStrError::InvalidRange(s) => write!(f, "invalid day range `{s}`"),
+            DaySetFromStrError::InvalidThreshold(s) => {
+                write!(
+                    f,
+                    "invalid threshold `{s}`, expecting a duration like `250ms`"
+                )
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{DaySet, DaySetFromStrError};
+    use crate::day;
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::Day;
+
+    fn resolve(s: &str, timings: &Timings) -> Vec<u8> {
+        let days: HashSet<Day> = s.parse::<DaySet>().unwrap().resolve(timings);
+        let mut days: Vec<u8> = days.into_iter().map(Day::into_inner).collect();
+        days.sort_unstable();
+        days
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("1s".into()),
+                    part_2: Some("1s".into()),
+                    total_nanos: 2e+9,
+                },
+                Timing {
+                    day: day!(3),
+                    part_1: Some("200ms".into()),
+                    part_2: None,
+                    total_nanos: 2e+8,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_single_days_and_ranges() {
+        let timings = Timings::default();
+        assert_eq!(resolve("7", &timings), [7]);
+        assert_eq!(
+            resolve("1-5,7,12..=15", &timings),
+            [1, 2, 3, 4, 5, 7, 12, 13, 14, 15]
+        );
+        assert_eq!(resolve("20..23", &timings), [20, 21, 22]);
+        assert_eq!(resolve("3, 1-2", &timings), [1, 2, 3]);
+    }
+
+    #[test]
+    fn parses_keywords() {
+        let timings = Timings::default();
+        assert_eq!(resolve("all", &timings).len(), 25);
+        assert_eq!(resolve("odd", &timings).len(), 13);
+        assert_eq!(resolve("even,1", &timings).len(), 13);
+    }
+
+    #[test]
+    fn uses_timings() {
+        let timings = get_mock_timings();
+        assert_eq!(resolve("unsolved", &timings).len(), 23);
+        assert_eq!(
+            resolve("unbenched", &timings),
+            resolve("unsolved", &timings)
+        );
+        assert_eq!(resolve("slow", &timings), [2, 3]);
+        assert_eq!(resolve("slow>1s", &timings), [2]);
+        assert_eq!(resolve("slow>10ms", &timings), [1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(
+            "26".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidDay("26".into()))
+        );
+        assert_eq!(
+            "5-1".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidRange("5-1".into()))
+        );
+        assert_eq!("1,,2".parse::<DaySet>(), Err(DaySetFromStrError::Empty));
+        assert_eq!(
+            "slow>fast".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidThreshold("fast".into()))
+        );
+    }
+}
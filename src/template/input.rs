@@ -0,0 +1,110 @@
+use core::fmt;
+use core::ops::Deref;
+
+use crate::grid::Grid;
+
+/// The text of a puzzle input or example, it derefs to [`str`].
+///
+/// The newlines are normalized to `\n` and the trailing whitespaces are trimmed,
+/// both at the end of each line and at the end of the text,
+/// so that files saved on Windows or edited by hand parse like the original ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        let mut normalized = String::with_capacity(text.len());
+        for line in text.lines() {
+            normalized.push_str(line.trim_end());
+            normalized.push('\n');
+        }
+        normalized.truncate(normalized.trim_end().len());
+
+        Self { text: normalized }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Iterates over the groups of lines separated by blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = &str> + '_ {
+        self.text
+            .split("\n\n")
+            .map(|block| block.trim_matches('\n'))
+            .filter(|block| !block.is_empty())
+    }
+
+    /// Iterates over the lines with their number, starting at 1.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> + '_ {
+        self.text.lines().enumerate().map(|(i, line)| (i + 1, line))
+    }
+
+    /// Parses the input as an ascii map.
+    #[must_use]
+    pub fn grid(&self) -> Grid<u8> {
+        Grid::parse_ascii(&self.text, |c| c)
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self::new(&text)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_newlines_and_trailing_whitespaces() {
+        let input = Input::new("#.# \r\n.#.\r\n\r\n  \r\nabc\t\r\n\r\n");
+        assert_eq!(input.as_str(), "#.#\n.#.\n\n\nabc");
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let input = Input::new("a\nb\n\nc\n \n\n\nd\n");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn numbers_lines() {
+        let input = Input::new("a\r\nb");
+        assert_eq!(
+            input.numbered_lines().collect::<Vec<_>>(),
+            [(1, "a"), (2, "b")]
+        );
+    }
+}
@@ -5,35 +5,42 @@ pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use day_set::*;
+pub use input::*;
 
 mod day;
+mod day_set;
+mod input;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod stats;
+mod submissions;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file to an [`Input`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, day: Day) -> Input {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    Input::from(f.expect("could not open input file"))
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file to an [`Input`], appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> Input {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    Input::from(f.expect("could not open input file"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
@@ -75,7 +82,11 @@ macro_rules! solution {
         fn main() {
             use $crate::template::runner::*;
             let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            // The closure lets `$func` take either `&Input` or `&str`.
+            let results = [ $( run_part(|input: &$crate::template::Input| $func(input), &input, DAY, $part), )* ];
+            if results.iter().any(Result::is_err) {
+                std::process::exit(101);
+            }
         }
     };
 }
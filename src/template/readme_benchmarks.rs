@@ -30,8 +30,8 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -81,11 +81,16 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+/// Replace the table delimited by `marker` with `table`.
+pub fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+    replace_table(s, MARKER, &table)
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
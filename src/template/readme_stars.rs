@@ -0,0 +1,200 @@
+/// Module that updates the readme with the stars earned, from the locally recorded submissions
+/// and the stars already in the table.
+/// The table has the same format as the one generated by `aoc-readme-stars`.
+use std::{collections::HashSet, fs};
+
+use crate::template::readme_benchmarks::{replace_table, Error};
+use crate::template::submissions::Submissions;
+use crate::template::{all_days, aoc_cli, Day};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Parses the stars of the current table, as `(day, part)`.
+///
+/// They are kept, so that the stars earned before submissions were recorded locally
+/// (or on another machine) aren't lost.
+fn parse_stars(readme: &str) -> HashSet<(Day, u8)> {
+    let Some(table) = readme.split(MARKER).nth(1) else {
+        return HashSet::new();
+    };
+
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut cells = line.split('|').skip(1);
+            let day = cells
+                .next()?
+                .trim()
+                .strip_prefix("[Day ")?
+                .split(']')
+                .next()?
+                .parse::<Day>()
+                .ok()?;
+            Some((day, cells))
+        })
+        .flat_map(|(day, cells)| {
+            (1..=2)
+                .zip(cells)
+                .filter(|(_, cell)| cell.contains('⭐'))
+                .map(move |(part, _)| (day, part))
+        })
+        .collect()
+}
+
+/// Lists the days that have at least one star, like `aoc-readme-stars`.
+fn construct_table(year: u16, stars: &HashSet<(Day, u8)>) -> String {
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let star = |day, part| {
+        if stars.contains(&(day, part)) {
+            "⭐"
+        } else {
+            " "
+        }
+    };
+
+    for day in all_days() {
+        if !stars.contains(&(day, 1)) && !stars.contains(&(day, 2)) {
+            continue;
+        }
+
+        let day_number = day.into_inner();
+        lines.push(format!(
+            "| [Day {day_number}](https://adventofcode.com/{year}/day/{day_number}) | {} | {} |",
+            star(day, 1),
+            star(day, 2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: u16, submissions: &Submissions) -> Result<(), Error> {
+    let mut stars = parse_stars(s);
+    stars.extend(submissions.data.iter().map(|s| (s.day, s.part)));
+
+    let table = construct_table(year, &stars);
+    replace_table(s, MARKER, &table)
+}
+
+/// Reads the stars of the README table, as `(day, part)`,
+/// an unreadable README has no stars.
+pub fn read() -> HashSet<(Day, u8)> {
+    fs::read_to_string("README.md")
+        .map(|readme| parse_stars(&readme))
+        .unwrap_or_default()
+}
+
+pub fn update(submissions: &Submissions) -> Result<(), Error> {
+    let year = aoc_cli::get_year()
+        .ok_or_else(|| Error::Parser("Could not get the year from `AOC_YEAR`.".into()))?;
+
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, submissions)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::{
+        day,
+        template::submissions::{Submission, Submissions},
+    };
+
+    fn get_mock_submissions() -> Submissions {
+        let submission = |day, part| Submission {
+            day,
+            part,
+            answer: "42".into(),
+            accepted_at: 0,
+        };
+
+        Submissions {
+            data: vec![
+                submission(day!(1), 1),
+                submission(day!(1), 2),
+                submission(day!(2), 1),
+                submission(day!(4), 2),
+            ],
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, 2024, &get_mock_submissions()).unwrap();
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, 2024, &get_mock_submissions()).unwrap();
+        update_content(&mut s, 2024, &get_mock_submissions()).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## 2024 Results").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn keeps_existing_stars() {
+        let mut s = [
+            MARKER,
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2024/day/3) | ⭐ |   |",
+            MARKER,
+        ]
+        .join("\n");
+        update_content(&mut s, 2024, &get_mock_submissions()).unwrap();
+        let expected = [
+            MARKER,
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2024/day/3) | ⭐ |   |",
+            "| [Day 4](https://adventofcode.com/2024/day/4) |   | ⭐ |",
+            MARKER,
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, 2024, &get_mock_submissions()).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- advent_readme_stars table --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "| [Day 4](https://adventofcode.com/2024/day/4) |   | ⭐ |",
+            "<!--- advent_readme_stars table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
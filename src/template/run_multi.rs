@@ -1,4 +1,4 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, path::Path};
 
 use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
@@ -10,29 +10,45 @@ use super::{
 pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
-    let mut need_space = false;
-
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    // NOTE: build every solution once, so we don't pay the cargo overhead for each day.
+    let executables = match child_commands::build_solutions(&days, is_release) {
+        Ok(executables) => executables,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e:?}");
+            std::process::exit(1);
+        }
+    };
+
+    let mut need_space = false;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    days.into_iter().for_each(|day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        let output = match executables.get(&day) {
+            Some(executable) => child_commands::run_solution(executable, is_timed).unwrap(),
+            None if Path::new(&get_path_for_bin(day)).exists() => {
+                println!("Build failed.");
+                return;
             }
-        });
+            None => vec![],
+        };
+
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, day);
+            timings.push(val);
+        }
+    });
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -70,36 +86,118 @@ pub mod child_commands {
     use super::{get_path_for_bin, Error};
     use crate::template::Day;
     use std::{
+        collections::HashMap,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        str::FromStr,
         thread,
     };
+    use tinyjson::JsonValue;
+
+    /// Build the solution binaries of `days` with a single cargo invocation,
+    /// forwarding the features this binary was built with.
+    ///
+    /// Returns the path of the executable of each day that was built.
+    /// The days that have not been scaffolded or failed to compile are missing.
+    pub fn build_solutions(days: &[Day], is_release: bool) -> Result<HashMap<Day, PathBuf>, Error> {
+        let bins: Vec<String> = days
+            .iter()
+            // skip days that have not been scaffolded yet.
+            .filter(|day| Path::new(&get_path_for_bin(**day)).exists())
+            .map(ToString::to_string)
+            .collect();
+
+        // NOTE: without any `--bin`, cargo would build every target.
+        if bins.is_empty() {
+            return Ok(HashMap::new());
+        }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        let mut args = vec![
+            "build".to_string(),
+            "--quiet".to_string(),
+            // NOTE: a compile error in one day shouldn't prevent the other days from running.
+            "--keep-going".to_string(),
+            "--message-format=json-render-diagnostics".to_string(),
+        ];
+
+        for bin in bins {
+            args.push("--bin".to_string());
+            args.push(bin);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let features = active_features();
+        if !features.is_empty() {
+            args.push("--features".to_string());
+            args.push(features.join(","));
+        }
 
         if is_release {
-            args.push("--release");
+            args.push("--release".to_string());
+        }
+
+        // NOTE: the diagnostics are rendered to stderr, stdout only contains the JSON messages.
+        let output = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        let executables = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_artifact)
+            .collect();
+
+        Ok(executables)
+    }
+
+    /// The features of the crate that are enabled in this binary.
+    fn active_features() -> Vec<&'static str> {
+        [
+            ("dhat-heap", cfg!(feature = "dhat-heap")),
+            ("today", cfg!(feature = "today")),
+            ("test_lib", cfg!(feature = "test_lib")),
+        ]
+        .into_iter()
+        .filter_map(|(feature, is_active)| is_active.then_some(feature))
+        .collect()
+    }
+
+    /// Parse a `compiler-artifact` message of cargo into the day and the path of its executable.
+    fn parse_artifact(line: &str) -> Option<(Day, PathBuf)> {
+        let json = JsonValue::from_str(line).ok()?;
+        let json = json.get::<HashMap<String, JsonValue>>()?;
+
+        if json.get("reason")?.get::<String>()? != "compiler-artifact" {
+            return None;
         }
 
+        let executable = json.get("executable")?.get::<String>()?;
+
+        let day = json
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?
+            .parse::<Day>()
+            .ok()?;
+
+        Some((day, PathBuf::from(executable)))
+    }
+
+    /// Run the solution executable of a given day
+    pub fn run_solution(executable: &Path, is_timed: bool) -> Result<Vec<String>, Error> {
+        let mut args = vec![];
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -174,7 +272,7 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
@@ -205,10 +303,43 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::path::PathBuf;
+
+        use super::{parse_artifact, parse_exec_time};
 
         use crate::day;
 
+        #[test]
+        fn parses_bin_artifacts() {
+            let res = parse_artifact(
+                r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"07"},"executable":"/repo/target/release/07","fresh":true}"#,
+            );
+            assert_eq!(
+                res,
+                Some((day!(7), PathBuf::from("/repo/target/release/07")))
+            );
+        }
+
+        #[test]
+        fn ignores_other_artifacts() {
+            assert_eq!(
+                parse_artifact(
+                    r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"advent_of_code"},"executable":"/repo/target/release/advent_of_code"}"#,
+                ),
+                None
+            );
+            assert_eq!(
+                parse_artifact(
+                    r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"07"},"executable":null}"#,
+                ),
+                None
+            );
+            assert_eq!(
+                parse_artifact(r#"{"reason":"build-finished","success":true}"#),
+                None
+            );
+        }
+
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
@@ -239,6 +370,21 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_panicked_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: ✖ panicked at src/bin/01.rs:5:45: called `Option::unwrap()` on a `None` value".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130000_f64);
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
@@ -2,23 +2,80 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::process::Output;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::readme_stars;
+use crate::template::submissions::{Submission, Submissions};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// A panic that occurred while running a solution part.
+#[derive(Debug, Clone)]
+pub struct PartPanic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Display for PartPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+/// Run a solution part, print its result and submit it if requested.
+///
+/// A panic of the part is caught and reported, so the other part can still run.
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+) -> Result<(), PartPanic> {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        match run_timed(func, input, |result| print_result(result, &part_str, "")) {
+            Ok(run) => run,
+            Err(panic) => {
+                println!("{part_str}: ✖ {panic}");
+                return Err(panic);
+            }
+        };
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if let Some(Ok(output)) = submit_result(&result, day, part) {
+            if aoc_cli::is_answer_accepted(&output) {
+                record_submission(day, part, result.to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Store an accepted answer, so its solve time is known by the `stats` command,
+/// and update the stars of the readme.
+fn record_submission(day: Day, part: u8, answer: String) {
+    let mut submissions = Submissions::read_from_file();
+    submissions.record(Submission::accepted_now(day, part, answer));
+
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store the accepted submission: {e}");
+        return;
+    }
+
+    if readme_stars::update(&submissions).is_err() {
+        eprintln!("Failed to store updated stars.");
     }
 }
 
@@ -29,7 +86,7 @@ fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> Result<(T, Duration, u128), PartPanic> {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -37,19 +94,69 @@ fn run_timed<I: Clone, T>(
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        catch_panic(|| func(input))?
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
     let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+        // NOTE: end the line of the intermediate result before the panic is reported.
+        catch_panic(|| bench(func, input, &base_time)).inspect_err(|_| println!())?
     } else {
         (base_time, 1)
     };
 
-    (result, run.0, run.1)
+    Ok((result, run.0, run.1))
+}
+
+/// Whether a panic is currently expected by [`catch_panic`].
+static IS_CATCHING_PANIC: AtomicBool = AtomicBool::new(false);
+
+/// The location of the last panic caught by [`catch_panic`].
+static PANIC_LOCATION: Mutex<Option<String>> = Mutex::new(None);
+
+/// Install, once, a panic hook that records the location of the panics caught by [`catch_panic`]
+/// instead of printing them, the other panics still go to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING_PANIC.load(Ordering::SeqCst) {
+                if let Ok(mut location) = PANIC_LOCATION.lock() {
+                    *location = info.location().map(ToString::to_string);
+                }
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+/// Run `f`, catching an unwinding panic instead of letting it reach the default hook.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, PartPanic> {
+    install_panic_hook();
+
+    IS_CATCHING_PANIC.store(true, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    IS_CATCHING_PANIC.store(false, Ordering::SeqCst);
+
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".into());
+
+        // NOTE: keep the failure on a single line.
+        let message = message.lines().next().unwrap_or_default().to_string();
+
+        let location = PANIC_LOCATION.lock().ok().and_then(|mut l| l.take());
+
+        PartPanic { message, location }
+    })
 }
 
 fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
@@ -133,7 +240,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
-    result: T,
+    result: &T,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -0,0 +1,389 @@
+/// Module that gathers what is known locally about each day into a progress dashboard.
+use std::{collections::HashSet, fs, time::Duration};
+
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::submissions::Submissions;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Items of the library that are part of the template rather than solution helpers:
+/// the `template` module and the macros it exports at the root of the crate.
+const TEMPLATE_ITEMS: &[&str] = &["template", "solution", "day", "day_from_file_name"];
+
+/// The number of days listed in the "slowest days" section.
+const SLOWEST_DAYS_COUNT: usize = 5;
+
+/// Represents what is known about a single day.
+#[derive(Clone, Debug)]
+pub struct DayStats {
+    pub day: Day,
+    pub parts: [PartStats; 2],
+    pub total_nanos: Option<f64>,
+    pub lines_of_code: Option<usize>,
+    pub helpers: Vec<String>,
+}
+
+/// Represents what is known about a single part.
+#[derive(Clone, Debug, Default)]
+pub struct PartStats {
+    pub is_solved: bool,
+    pub timing: Option<String>,
+    /// The time between the unlock of the puzzle and the accepted answer, in seconds.
+    pub solve_secs: Option<u64>,
+}
+
+impl DayStats {
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|part| part.is_solved).count()
+    }
+}
+
+/// Collects the stats of every day that has been started.
+///
+/// A part is solved if it has an accepted submission or a star in `readme_stars`,
+/// which backfills the parts solved before the submissions were recorded.
+pub fn collect(
+    timings: &Timings,
+    submissions: &Submissions,
+    readme_stars: &HashSet<(Day, u8)>,
+    year: Option<u16>,
+) -> Vec<DayStats> {
+    all_days()
+        .filter_map(|day| {
+            let source = fs::read_to_string(get_path_for_bin(day)).ok();
+            let timing = timings.data.iter().find(|t| t.day == day);
+
+            let parts = [1, 2].map(|part| {
+                let submission = submissions.get(day, part);
+                let timing = timing.and_then(|t| match part {
+                    1 => t.part_1.clone(),
+                    _ => t.part_2.clone(),
+                });
+
+                PartStats {
+                    // NOTE: a timing only means the solution runs, not that its answer is right.
+                    is_solved: submission.is_some() || readme_stars.contains(&(day, part)),
+                    timing,
+                    solve_secs: submission.zip(year).and_then(|(submission, year)| {
+                        submission
+                            .accepted_at
+                            .checked_sub(unlock_timestamp(year, day))
+                    }),
+                }
+            });
+
+            if source.is_none() && timing.is_none() && parts.iter().all(|p| !p.is_solved) {
+                return None;
+            }
+
+            Some(DayStats {
+                day,
+                parts,
+                total_nanos: timing.map(|t| t.total_nanos),
+                lines_of_code: source.as_deref().map(count_lines_of_code),
+                helpers: source.as_deref().map(used_helpers).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the unix timestamp at which the puzzle of `day` unlocks.
+///
+/// Puzzles unlock at midnight EST (UTC-5).
+fn unlock_timestamp(year: u16, day: Day) -> u64 {
+    let is_leap = |y: u64| (y.is_multiple_of(4) && !y.is_multiple_of(100)) || y.is_multiple_of(400);
+
+    let year = u64::from(year);
+    let days_before_year: u64 = (1970..year)
+        .map(|y| if is_leap(y) { 366 } else { 365 })
+        .sum();
+    let days_before_december = if is_leap(year) { 335 } else { 334 };
+    let days = days_before_year + days_before_december + u64::from(day.into_inner()) - 1;
+
+    days * 86_400 + 5 * 3_600
+}
+
+/// Counts the lines that are neither blank nor comments.
+fn count_lines_of_code(source: &str) -> usize {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count()
+}
+
+/// Lists the items of the library used by a solution, excluding the template.
+fn used_helpers(source: &str) -> Vec<String> {
+    const PREFIX: &str = "advent_of_code::";
+
+    let mut helpers: Vec<String> = source
+        .match_indices(PREFIX)
+        .flat_map(|(i, _)| parse_use_tree(&source[i + PREFIX.len()..]).0)
+        .filter(|path| {
+            let root = path.split("::").next().unwrap_or_default();
+            !TEMPLATE_ITEMS.contains(&root)
+        })
+        .collect();
+
+    helpers.sort_unstable();
+    helpers.dedup();
+    helpers
+}
+
+/// Parses a path or a use tree (e.g. `{a, b::{c, d}}`) into the list of paths it contains.
+///
+/// Returns the paths and the remaining input.
+fn parse_use_tree(s: &str) -> (Vec<String>, &str) {
+    let s = s.trim_start();
+
+    if let Some(mut s) = s.strip_prefix('{') {
+        let mut paths = vec![];
+        loop {
+            let (mut items, rest) = parse_use_tree(s);
+            paths.append(&mut items);
+            let rest = rest.trim_start();
+
+            if let Some(rest) = rest.strip_prefix(',') {
+                s = rest;
+            } else {
+                return (paths, rest.strip_prefix('}').unwrap_or(rest));
+            }
+        }
+    }
+
+    let end = s
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    let (ident, rest) = s.split_at(end);
+
+    if ident.is_empty() {
+        return (vec![], rest);
+    }
+
+    if let Some(rest) = rest.strip_prefix("::") {
+        let (paths, rest) = parse_use_tree(rest);
+        let paths = paths
+            .into_iter()
+            .map(|path| format!("{ident}::{path}"))
+            .collect();
+        return (paths, rest);
+    }
+
+    // skip renaming, e.g. `glam::IVec2 as Pos`.
+    let rest = match rest.trim_start().strip_prefix("as ") {
+        Some(rest) => parse_use_tree(rest).1,
+        None => rest,
+    };
+
+    (vec![ident.to_string()], rest)
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+fn format_solve_time(secs: u64) -> String {
+    if secs >= 24 * 3_600 {
+        return ">24h".into();
+    }
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3_600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+}
+
+fn format_part(part: &PartStats) -> String {
+    let star = if part.is_solved { "⭐" } else { "  " };
+    let timing = part.timing.as_deref().unwrap_or("-");
+    let solve_time = part
+        .solve_secs
+        .map_or_else(|| "-".into(), format_solve_time);
+    format!("{star} {timing:>9} {solve_time:>8}")
+}
+
+/// Prints the dashboard of the year's progress.
+pub fn print(stats: &[DayStats]) {
+    println!(
+        "{ANSI_BOLD}{:<4} {:<21} {:<21} {:>9} {:>5}  Helpers{ANSI_RESET}",
+        "Day", "Part 1", "Part 2", "Total", "LoC"
+    );
+
+    for day in stats {
+        let total = day.total_nanos.map_or_else(|| "-".into(), format_nanos);
+        let lines_of_code = day
+            .lines_of_code
+            .map_or_else(|| "-".into(), |loc| loc.to_string());
+        let helpers = if day.helpers.is_empty() {
+            "-".into()
+        } else {
+            day.helpers.join(", ")
+        };
+
+        println!(
+            "{:<4} {} {} {total:>9} {lines_of_code:>5}  {ANSI_ITALIC}{helpers}{ANSI_RESET}",
+            day.day,
+            format_part(&day.parts[0]),
+            format_part(&day.parts[1]),
+        );
+    }
+
+    let stars: usize = stats.iter().map(DayStats::stars).sum();
+    let total_millis = stats
+        .iter()
+        .filter_map(|d| d.total_nanos)
+        .fold(0_f64, |acc, nanos| acc + nanos)
+        / 1_000_000_f64;
+    let lines_of_code: usize = stats.iter().filter_map(|d| d.lines_of_code).sum();
+
+    println!();
+    println!("{ANSI_BOLD}Stars:{ANSI_RESET} {stars}/50 ⭐");
+    println!("{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+    println!("{ANSI_BOLD}Lines of code:{ANSI_RESET} {lines_of_code}");
+
+    let mut slowest: Vec<_> = stats
+        .iter()
+        .filter_map(|d| Some((d.day, d.total_nanos?)))
+        .collect();
+    slowest.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+
+    if !slowest.is_empty() {
+        println!();
+        println!("{ANSI_BOLD}Slowest days{ANSI_RESET}");
+        for (day, nanos) in slowest.into_iter().take(SLOWEST_DAYS_COUNT) {
+            println!(
+                "Day {day}: {ANSI_ITALIC}{}{ANSI_RESET}",
+                format_nanos(nanos)
+            );
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+    use std::fs;
+
+    use super::{
+        collect, count_lines_of_code, format_solve_time, unlock_timestamp, used_helpers,
+        TEMPLATE_ITEMS,
+    };
+    use crate::day;
+    use crate::template::submissions::{Submission, Submissions};
+    use crate::template::timings::{Timing, Timings};
+
+    #[test]
+    fn computes_unlock_timestamp() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(unlock_timestamp(2024, day!(1)), 1_733_029_200);
+        // 2023-12-25T05:00:00Z
+        assert_eq!(unlock_timestamp(2023, day!(25)), 1_703_480_400);
+    }
+
+    #[test]
+    fn only_counts_accepted_answers_as_solved() {
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some("10ns".into()),
+                part_2: Some("20ns".into()),
+                total_nanos: 30.0,
+            }],
+        };
+        let submissions = Submissions {
+            data: vec![Submission {
+                day: day!(1),
+                part: 1,
+                answer: "42".into(),
+                accepted_at: 1_733_029_260,
+            }],
+        };
+
+        let stats = collect(&timings, &submissions, &HashSet::new(), Some(2024));
+        let day_1 = stats.iter().find(|s| s.day == day!(1)).unwrap();
+        assert!(day_1.parts[0].is_solved);
+        assert!(!day_1.parts[1].is_solved);
+        assert_eq!(day_1.parts[1].timing.as_deref(), Some("20ns"));
+        assert_eq!(day_1.stars(), 1);
+
+        // The stars of the README backfill the parts without a submission.
+        let readme_stars = HashSet::from([(day!(1), 2)]);
+        let stats = collect(&timings, &submissions, &readme_stars, Some(2024));
+        let day_1 = stats.iter().find(|s| s.day == day!(1)).unwrap();
+        assert_eq!(day_1.stars(), 2);
+        assert_eq!(day_1.parts[1].solve_secs, None);
+    }
+
+    #[test]
+    fn template_items_match_the_template() {
+        let mut items = vec!["template".to_string()];
+        for entry in fs::read_dir("src/template").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+
+            let source = fs::read_to_string(path).unwrap();
+            let mut lines = source.lines().map(str::trim);
+            while let Some(line) = lines.next() {
+                if line == "#[macro_export]" {
+                    let name = lines
+                        .next()
+                        .and_then(|l| l.strip_prefix("macro_rules! "))
+                        .and_then(|l| l.strip_suffix(" {"))
+                        .unwrap();
+                    items.push(name.into());
+                }
+            }
+        }
+
+        items.sort_unstable();
+        let mut expected = TEMPLATE_ITEMS.to_vec();
+        expected.sort_unstable();
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn formats_solve_times() {
+        assert_eq!(format_solve_time(3_725), "01:02:05");
+        assert_eq!(format_solve_time(86_400), ">24h");
+    }
+
+    #[test]
+    fn counts_lines_of_code() {
+        let source = "use foo;\n\n// comment\nfn main() {\n    bar();\n}\n";
+        assert_eq!(count_lines_of_code(source), 4);
+    }
+
+    #[test]
+    fn lists_used_helpers() {
+        let source = r"
+            use advent_of_code::{array2d::{Array2D, Size}, Dir};
+            use advent_of_code::ascii_map_size;
+
+            advent_of_code::solution!();
+
+            fn foo() {
+                let circle = advent_of_code::points::circle(2);
+                let input = advent_of_code::template::read_file(1);
+            }
+        ";
+        assert_eq!(
+            used_helpers(source),
+            [
+                "Dir",
+                "array2d::Array2D",
+                "array2d::Size",
+                "ascii_map_size",
+                "points::circle"
+            ]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Represents an answer accepted by the server for a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// The time the answer was accepted, in seconds since the unix epoch.
+    pub accepted_at: u64,
+}
+
+/// Represents the accepted answers of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submission {
+    /// Creates a [`Submission`] accepted now.
+    pub fn accepted_now(day: Day, part: u8, answer: String) -> Self {
+        let accepted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            day,
+            part,
+            answer,
+            accepted_at,
+        }
+    }
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns empty submissions.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(SUBMISSIONS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Records a submission, keeping the first one accepted for a given part.
+    pub fn record(&mut self, submission: Submission) {
+        if self.get(submission.day, submission.part).is_some() {
+            return;
+        }
+
+        self.data.push(submission);
+        self.data.sort_unstable_by_key(|s| (s.day, s.part));
+    }
+
+    /// Returns the accepted submission of a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&Submission> {
+        self.data.iter().find(|s| s.day == day && s.part == part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        #[allow(clippy::cast_precision_loss)]
+        let accepted_at = value.accepted_at as f64;
+        map.insert("accepted_at".into(), JsonValue::Number(accepted_at));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|&&part| part == 1.0 || part == 2.0)
+            .map(|&part| if part == 1.0 { 1 } else { 2 })
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let accepted_at = json
+            .get("accepted_at")
+            .and_then(|v| v.get::<f64>())
+            .filter(|&&t| t >= 0.0)
+            .map(|&t| t as u64)
+            .ok_or("Expected submission.accepted_at to be a timestamp.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            accepted_at,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Submission, Submissions};
+
+    #[test]
+    fn handles_json_submissions() {
+        let json = r#"{ "data": [{ "day": "01", "part": 2, "answer": "42", "accepted_at": 1733029500 }] }"#.to_string();
+        let submissions = Submissions::try_from(json).unwrap();
+        assert_eq!(
+            submissions.data,
+            [Submission {
+                day: day!(1),
+                part: 2,
+                answer: "42".into(),
+                accepted_at: 1_733_029_500,
+            }]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_part() {
+        let json = r#"{ "data": [{ "day": "01", "part": 3, "answer": "42", "accepted_at": 0 }] }"#
+            .to_string();
+        Submissions::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn keeps_first_accepted_submission() {
+        let mut submissions = Submissions::default();
+        submissions.record(Submission {
+            day: day!(2),
+            part: 1,
+            answer: "1".into(),
+            accepted_at: 10,
+        });
+        submissions.record(Submission {
+            day: day!(1),
+            part: 1,
+            answer: "2".into(),
+            accepted_at: 20,
+        });
+        submissions.record(Submission {
+            day: day!(2),
+            part: 1,
+            answer: "3".into(),
+            accepted_at: 30,
+        });
+
+        assert_eq!(submissions.data.len(), 2);
+        assert_eq!(submissions.data[0].day, day!(1));
+        assert_eq!(submissions.get(day!(2), 1).unwrap().answer, "1");
+    }
+}
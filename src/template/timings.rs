@@ -51,7 +51,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,10 +60,10 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, day: &Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| &t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -0,0 +1,192 @@
+use crate::array2d::Array2D;
+use crate::region::Region;
+
+/// A disjoint-set of the elements `0..len`, with path compression and union by rank.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    sets_count: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets.
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            sets_count: len,
+        }
+    }
+
+    /// The number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    #[inline]
+    pub fn sets_count(&self) -> usize {
+        self.sets_count
+    }
+
+    /// Returns the representative of the set of `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression
+        let mut x = x;
+        while self.parents[x] != root {
+            x = std::mem::replace(&mut self.parents[x], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets of `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.sets_count -= 1;
+
+        true
+    }
+
+    #[inline]
+    pub fn is_same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set of `x`.
+    #[inline]
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The connected components of an [`Array2D`], see [`connected_components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    labels: Array2D<usize>,
+    sizes: Vec<usize>,
+}
+
+impl Components {
+    /// The number of components.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// The label of the component of each cell.
+    ///
+    /// Labels are in `0..count`, in the order the components are first met row by row.
+    #[inline]
+    pub fn labels(&self) -> &Array2D<usize> {
+        &self.labels
+    }
+
+    #[inline]
+    pub fn label(&self, row: usize, column: usize) -> Option<usize> {
+        self.labels.get(row, column).copied()
+    }
+
+    /// The number of cells of each component, indexed by label.
+    #[inline]
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// The `(row, column)` of the cells of each component, indexed by label.
+    pub fn positions(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut positions: Vec<_> = self.sizes.iter().map(|&s| Vec::with_capacity(s)).collect();
+        for (pos, &label) in self.labels.iter_with_index() {
+            positions[label].push(pos);
+        }
+        positions
+    }
+
+    /// The [`Region`] of each component, indexed by label.
+    pub fn regions(&self) -> Vec<Region> {
+        self.positions()
+            .into_iter()
+            .map(Region::from_iter)
+            .collect()
+    }
+}
+
+/// Groups the cells of `array` into components, where two orthogonal neighbors
+/// are in the same component if `is_adjacent` returns `true` for them.
+///
+/// Works with a [`Grid`](crate::grid::Grid) as well, through deref.
+pub fn connected_components<T>(
+    array: &Array2D<T>,
+    mut is_adjacent: impl FnMut(&T, &T) -> bool,
+) -> Components {
+    let size = array.size();
+    let columns = size.columns_count();
+    let mut sets = UnionFind::new(size.items_count());
+
+    for ((row, column), item) in array.iter_with_index() {
+        let index = row * columns + column;
+
+        if let Some(right) = array.get(row, column + 1) {
+            if is_adjacent(item, right) {
+                sets.union(index, index + 1);
+            }
+        }
+
+        if let Some(down) = array.get(row + 1, column) {
+            if is_adjacent(item, down) {
+                sets.union(index, index + columns);
+            }
+        }
+    }
+
+    let mut root_labels = vec![usize::MAX; sets.len()];
+    let mut sizes = Vec::with_capacity(sets.sets_count());
+
+    let labels = Array2D::from_elem(size, |row, column| {
+        let root = sets.find(row * columns + column);
+        if root_labels[root] == usize::MAX {
+            root_labels[root] = sizes.len();
+            sizes.push(sets.set_size(root));
+        }
+        root_labels[root]
+    });
+
+    Components { labels, sizes }
+}